@@ -6,15 +6,35 @@
     MIT License
 */
 
-use soroban_sdk::{symbol_short, token, Address, Env, Symbol};
+use core::cmp::Reverse;
+use soroban_sdk::{symbol_short, token, vec, Address, Env, Symbol, Vec};
 
 use super::behavior_ascending_price::*;
+use super::behavior_candle::*;
+use super::behavior_clock::*;
 use super::behavior_descending_price::*;
-use crate::{storage::*, types::{AdminData, AuctionData, DataKey, BidData}};
+use super::behavior_discriminatory_price::*;
+use super::behavior_dutch_english::*;
+use super::behavior_fixed_price::*;
+use super::behavior_gradual_dutch::*;
+use super::behavior_offer::*;
+use super::behavior_reverse::*;
+use super::behavior_sealed_bid::*;
+use super::behavior_uniform_price::*;
+use super::behavior_variable_rate_gradual_dutch::*;
+use super::behavior_vickrey::*;
+use super::bid_increment::minimum_bid;
+use crate::{
+    storage::*,
+    types::{
+        AdminData, AuctionBehavior, AuctionData, BidData, CancellationPolicy, DataKey, FillData,
+        SellerData, SoftClose, TieBreak,
+    },
+};
 
 // Event topics.
-const AUCTION: Symbol = symbol_short!("AUCTION");
-const BID: Symbol = symbol_short!("BID");
+pub const AUCTION: Symbol = symbol_short!("AUCTION");
+pub const BID: Symbol = symbol_short!("BID");
 
 pub mod ledger_times {
     // Assuming 6 seconds average time per ledger.
@@ -22,96 +42,273 @@ pub mod ledger_times {
     pub const LEDGERS_PER_HOUR: u64 = LEDGERS_PER_MINUTE * 60;
     pub const LEDGERS_PER_DAY: u64 = LEDGERS_PER_HOUR * 24;
     pub const LEDGERS_PER_YEAR: u64 = LEDGERS_PER_DAY * 365;
+
+    // Couple hours added to storage lifetimes to avoid expiration with async resolve.
+    pub const EXPIRATION_BUFFER: u64 = 7200;
+
+    pub fn convert_seconds_to_ledgers(watermark: u64) -> u64 {
+        watermark
+            .checked_add(LEDGERS_PER_MINUTE - 1)
+            .and_then(|sum| sum.checked_div(LEDGERS_PER_MINUTE))
+            .expect("Invalid duration.")
+            .min(LEDGERS_PER_YEAR)
+    }
+}
+
+// Highest bid, the earliest bid winning ties.
+pub fn best_bid(auction_data: &AuctionData) -> Option<BidData> {
+    auction_data
+        .bids
+        .iter()
+        .max_by_key(|bid| (bid.amount, Reverse(bid.sequence)))
+}
+
+// Sequence number for the next bid on the auction.
+pub fn next_sequence(auction_data: &mut AuctionData) -> u64 {
+    let sequence = auction_data.bid_sequence;
+    auction_data.bid_sequence += 1;
+    sequence
+}
+
+// Whether the bid ties a bid from another buyer on auctions rejecting ties.
+// Multi-unit auctions compare unit prices rather than total amounts.
+pub fn is_rejected_tie(auction_data: &AuctionData, buyer: &Address, amount: i128, quantity: i128) -> bool {
+    let multi_unit = matches!(
+        auction_data.behavior,
+        AuctionBehavior::UniformPrice | AuctionBehavior::DiscriminatoryPrice
+    );
+    auction_data.tie_break == TieBreak::Rejected
+        && auction_data.bids.iter().any(|bid| {
+            bid.buyer != *buyer
+                && if multi_unit {
+                    bid.amount / bid.quantity == amount / quantity
+                } else {
+                    bid.amount == amount
+                }
+        })
+}
+
+// Applies the cancellation policy to the bid, paying the fee (if any)
+// from the escrowed bid. Returns the fee.
+pub fn cancellation_fee(env: &Env, auction_data: &AuctionData, bid: &BidData) -> i128 {
+    let cancellation = match &auction_data.cancellation {
+        CancellationPolicy::Restricted(cancellation) => cancellation,
+        CancellationPolicy::Free => return 0,
+    };
+
+    let now = env.ledger().timestamp();
+    if now + cancellation.cutoff >= auction_data.start_time + auction_data.duration {
+        panic!("Bid is irrevocable.");
+    }
+
+    if now <= bid.placed_at + cancellation.free_window {
+        return 0;
+    }
+
+    let fee = bid.amount * cancellation.fee_percent as i128 / 100;
+    if fee > 0 {
+        let recipient = if cancellation.fee_to_seller {
+            auction_data.seller.clone()
+        } else {
+            load_data::<DataKey, AdminData>(env, &DataKey::AdminData).admin
+        };
+        let market = token::Client::new(env, &auction_data.market);
+        market.transfer(&env.current_contract_address(), &recipient, &fee);
+    }
+    fee
+}
+
+// Deletes the auction and removes it from the seller auctions.
+pub fn delete_auction(env: &Env, auction_id: u64, auction_data: &AuctionData) {
+    delete_data::<DataKey, AuctionData>(env, &DataKey::AuctionData(auction_id));
+
+    let key = DataKey::SellerData(auction_data.seller.clone());
+    if let Some(mut seller_data) = load_data_or_else::<DataKey, SellerData, _, _>(env, &key, |opt| opt) {
+        if let Some(index) = seller_data.auctions.first_index_of(auction_id) {
+            seller_data.auctions.remove(index);
+        }
+        if seller_data.auctions.is_empty() {
+            delete_data::<DataKey, SellerData>(env, &key);
+        } else {
+            save_data::<DataKey, SellerData>(env, &key, &seller_data);
+        }
+    }
+}
+
+// Cancels the auction without settling it: all bids are refunded, the lot and
+// bundle are returned to the seller and only the cancelled event is published.
+pub fn cancel_auction<A: BaseAuction + ?Sized>(auction: &A, env: &Env, auction_id: u64) {
+    let auction_data = load_data::<DataKey, AuctionData>(env, &DataKey::AuctionData(auction_id));
+    for bid in auction_data.bids.iter().filter(|b| b.amount > 0) {
+        auction.refund_bid(env, auction_id, &auction_data, &bid);
+    }
+    auction.release_lot(env, &auction_data);
+    auction.deliver_bundle(env, &auction_data, &auction_data.seller);
+
+    delete_auction(env, auction_id, &auction_data);
+    env.events()
+        .publish((AUCTION, symbol_short!("cancelled")), auction_id);
+}
+
+// Extends the auction after a late bid according to its soft-close policy.
+pub fn extend_soft_close(env: &Env, auction_data: &mut AuctionData, window: u64) {
+    match &mut auction_data.soft_close {
+        SoftClose::Default => auction_data.duration += window,
+        SoftClose::Custom(soft_close) => {
+            if soft_close.max_extensions > 0 && soft_close.extensions >= soft_close.max_extensions {
+                return;
+            }
+
+            soft_close.extensions += 1;
+            auction_data.duration = if soft_close.reset {
+                (env.ledger().timestamp() - auction_data.start_time + window)
+                    .max(auction_data.duration)
+            } else {
+                auction_data.duration + window
+            };
+        }
+    }
 }
 
 pub trait BaseAuction {
-    fn start(&self, env: &Env, seller: &Address, auction_data: &AuctionData) {
-        if has_data::<DataKey, AuctionData>(&env, &DataKey::AuctionData(seller.clone())) {
-            panic!("Auction already running.");
+    fn start(&self, env: &Env, auction_id: u64, auction_data: &AuctionData) {
+        if auction_data.amount == 0
+            || auction_data.duration == 0
+            || !self.accepts_parameters(auction_data)
+        {
+            panic!("Invalid auction parameters.");
         }
 
-        if auction_data.amount == 0 || auction_data.duration == 0 {
+        if !auction_data.bundle.is_empty()
+            && (!self.supports_bundle(auction_data)
+                || auction_data.bundle.iter().any(|lot| lot.amount <= 0))
+        {
             panic!("Invalid auction parameters.");
         }
 
         // Transfer token to contract.
-        let token = token::Client::new(&env, &auction_data.token);
-        token.transfer(
-            &seller,
-            &env.current_contract_address(),
-            &auction_data.amount,
-        );
-        save_data::<DataKey, AuctionData>(env, &DataKey::AuctionData(seller.clone()), auction_data);
+        self.escrow_lot(env, auction_data);
 
-        fn convert_seconds_to_ledgers(watermark: u64) -> u64 {
-            watermark
-                .checked_add(ledger_times::LEDGERS_PER_MINUTE - 1)
-                .and_then(|sum| sum.checked_div(ledger_times::LEDGERS_PER_MINUTE))
-                .expect("Invalid duration.")
-                .min(ledger_times::LEDGERS_PER_YEAR)
+        // Transfer bundled tokens to contract.
+        for lot in auction_data.bundle.iter() {
+            let token = token::Client::new(env, &lot.token);
+            token.transfer(&auction_data.seller, &env.current_contract_address(), &lot.amount);
         }
+        save_data::<DataKey, AuctionData>(env, &DataKey::AuctionData(auction_id), auction_data);
+
+        // Index the auction by seller.
+        let key = DataKey::SellerData(auction_data.seller.clone());
+        let mut seller_data = load_data_or_else::<DataKey, SellerData, _, _>(env, &key, |opt| {
+            opt.unwrap_or_else(|| SellerData {
+                auctions: vec![env],
+            })
+        });
+        seller_data.auctions.push_back(auction_id);
+        save_data::<DataKey, SellerData>(env, &key, &seller_data);
+        self.bump_auction(env, auction_id, auction_data);
+
+        env.events()
+            .publish((AUCTION, symbol_short!("started")), auction_id);
+    }
 
-        // Bump the storage according to auction duration,
-        // adding a couple hours to avoid expiration with async resolve.
-        let expiration_buffer: u64 = 7200;
+    // Bumps the storage according to the time until the auction opens, its duration
+    // and settlement time, adding a couple hours to avoid expiration with async resolve.
+    fn bump_auction(&self, env: &Env, auction_id: u64, auction_data: &AuctionData) {
+        let lifetime = ledger_times::convert_seconds_to_ledgers(
+            auction_data.start_time.saturating_sub(env.ledger().timestamp())
+                + auction_data.duration
+                + self.settlement_time(auction_data)
+                + ledger_times::EXPIRATION_BUFFER,
+        );
         bump_data::<DataKey, AuctionData>(
             env,
-            &DataKey::AuctionData(seller.clone()),
-            convert_seconds_to_ledgers(auction_data.duration + expiration_buffer),
-            convert_seconds_to_ledgers(auction_data.duration + expiration_buffer)
+            &DataKey::AuctionData(auction_id),
+            lifetime,
+            lifetime
+        );
+        bump_data::<DataKey, SellerData>(
+            env,
+            &DataKey::SellerData(auction_data.seller.clone()),
+            lifetime,
+            lifetime
         );
+    }
 
-        env.events()
-            .publish((AUCTION, symbol_short!("started")), seller);
+    // Cancels the auction, refunding all bids and returning the lot to the seller.
+    fn cancel(&self, env: &Env, auction_id: u64) {
+        cancel_auction(self, env, auction_id);
     }
 
-    fn manage_bid(&self, env: &Env, seller: &Address, buyer: &Address, amount: i128) {
+    fn manage_bid(&self, env: &Env, auction_id: u64, buyer: &Address, amount: i128, quantity: i128) {
         // First check that the auction is resolved.
-        let resolved = self.resolve(env, seller);
+        let resolved = self.resolve(env, auction_id);
         if resolved {
             return;
         }
 
-        let mut auction_data = load_data::<DataKey, AuctionData>(env, &DataKey::AuctionData(seller.clone()));
-        let market = token::Client::new(&env, &auction_data.market);
+        let mut auction_data = load_data::<DataKey, AuctionData>(env, &DataKey::AuctionData(auction_id));
+
+        let (anti_snipe_time, extendable) = self.anti_snipe(env, &auction_data);
+        let closing = env.ledger().timestamp()
+            >= (auction_data.start_time + auction_data.duration).saturating_sub(anti_snipe_time);
 
         if amount == 0 {
             // Cancel existing bid if amount is zero.
+            // Bids are final once a non-extendable closing window is reached.
             if let Some(index) = auction_data
                 .bids
                 .iter()
-                .position(|b| b.amount > 0 && b.buyer == *buyer && !b.sniper)
+                .position(|b| b.amount > 0 && b.buyer == *buyer && !b.sniper && (extendable || !closing))
             {
-                let bid = &auction_data.bids.get_unchecked(index as u32);
-                market.transfer(&env.current_contract_address(), &buyer, &bid.amount);
+                let bid = auction_data.bids.get_unchecked(index as u32);
+                let fee = cancellation_fee(env, &auction_data, &bid);
+                self.refund_bid(
+                    env,
+                    auction_id,
+                    &auction_data,
+                    &BidData {
+                        amount: bid.amount - fee,
+                        ..bid
+                    },
+                );
                 auction_data.bids.remove(index as u32);
                 env.events()
-                    .publish((BID, symbol_short!("deleted")), seller);
+                    .publish((BID, symbol_short!("deleted")), auction_id);
             } else {
                 panic!("No bid to cancel.");
             }
-        } else if amount > 0 && amount >= auction_data.reserve_price {
+        } else if amount > 0
+            && quantity > 0
+            && quantity <= auction_data.amount
+            && self.accepts_bid(&auction_data, amount, quantity)
+        {
+            if is_rejected_tie(&auction_data, buyer, amount, quantity) {
+                panic!("Tied bids not allowed.");
+            }
+
             if !auction_data
                 .bids
                 .iter()
                 .any(|b| (b.buyer == *buyer && b.amount > 0) || (b.buyer == *buyer && b.sniper))
             {
-                market.transfer(&buyer, &env.current_contract_address(), &amount);
-
-                let anti_snipe_time =
-                    load_data::<DataKey, AdminData>(&env, &DataKey::AdminData).anti_snipe_time;
-                let sniper = env.ledger().timestamp()
-                    >= auction_data.start_time + auction_data.duration - anti_snipe_time;
-                if sniper {
-                    auction_data.duration += anti_snipe_time;
+                self.escrow_bid(env, &auction_data, buyer, amount, quantity);
+
+                let sniper = closing;
+                if sniper && extendable {
+                    extend_soft_close(env, &mut auction_data, anti_snipe_time);
                 }
 
+                let sequence = next_sequence(&mut auction_data);
                 auction_data.bids.push_back(BidData {
                     buyer: buyer.clone(),
                     amount,
+                    quantity,
                     sniper,
+                    timestamp: env.ledger().timestamp(),
+                    placed_at: env.ledger().timestamp(),
+                    sequence,
                 });
-                env.events().publish((BID, symbol_short!("added")), seller);
+                env.events().publish((BID, symbol_short!("added")), auction_id);
             } else {
                 panic!("Not allowed to place new bid.");
             }
@@ -119,108 +316,634 @@ pub trait BaseAuction {
             panic!("Invalid bid amount.");
         }
 
-        save_data::<DataKey, AuctionData>(env, &DataKey::AuctionData(seller.clone()), &auction_data);
-        self.resolve(env, seller);
+        save_data::<DataKey, AuctionData>(env, &DataKey::AuctionData(auction_id), &auction_data);
+        self.resolve(env, auction_id);
+    }
+
+    // Raises an existing bid to the new amount, escrowing only the difference.
+    // Late raises are subject to the same anti-snipe rules as new bids.
+    fn raise_bid(&self, env: &Env, auction_id: u64, buyer: &Address, amount: i128) {
+        // First check that the auction is resolved.
+        let resolved = self.resolve(env, auction_id);
+        if resolved {
+            return;
+        }
+
+        let mut auction_data = load_data::<DataKey, AuctionData>(env, &DataKey::AuctionData(auction_id));
+        let index = auction_data
+            .bids
+            .iter()
+            .position(|b| b.buyer == *buyer && b.amount > 0)
+            .unwrap_or_else(|| panic!("No bid to raise.")) as u32;
+        let mut bid = auction_data.bids.get_unchecked(index);
+
+        if amount <= bid.amount || !self.accepts_bid(&auction_data, amount, bid.quantity) {
+            panic!("Invalid bid amount.");
+        }
+
+        if is_rejected_tie(&auction_data, buyer, amount, bid.quantity) {
+            panic!("Tied bids not allowed.");
+        }
+
+        self.escrow_bid(env, &auction_data, buyer, amount - bid.amount, bid.quantity);
+
+        let (anti_snipe_time, extendable) = self.anti_snipe(env, &auction_data);
+        if env.ledger().timestamp()
+            >= (auction_data.start_time + auction_data.duration).saturating_sub(anti_snipe_time)
+        {
+            bid.sniper = true;
+            if extendable {
+                extend_soft_close(env, &mut auction_data, anti_snipe_time);
+            }
+        }
+
+        bid.amount = amount;
+        bid.timestamp = env.ledger().timestamp();
+        bid.sequence = next_sequence(&mut auction_data);
+        auction_data.bids.set(index, bid);
+        env.events().publish((BID, symbol_short!("raised")), auction_id);
+
+        save_data::<DataKey, AuctionData>(env, &DataKey::AuctionData(auction_id), &auction_data);
+        self.resolve(env, auction_id);
     }
 
-    fn finalize(&self, env: &Env, seller: &Address, winner: Option<&BidData>) -> bool {
-        let auction_data = load_data::<DataKey, AuctionData>(env, &DataKey::AuctionData(seller.clone()));
+    fn finalize(&self, env: &Env, auction_id: u64, winner: Option<&BidData>) -> bool {
         match winner {
-            Some(bid) => {
-                // We have a winner, transfer token to parties.
-                let admin_data = load_data::<DataKey, AdminData>(&env, &DataKey::AdminData);
-                let token = token::Client::new(&env, &auction_data.token);
-                let market = token::Client::new(&env, &auction_data.market);
-                let admin: Address = admin_data.admin;
-                let commission_rate: i128 = admin_data.commission_rate;
-                let admin_share = bid.amount * commission_rate / 100;
-                let seller_share = bid.amount - admin_share;
-
-                token.transfer(
-                    &env.current_contract_address(),
-                    &bid.buyer,
-                    &auction_data.amount,
-                );
-                market.transfer(&env.current_contract_address(), &admin, &admin_share);
-                market.transfer(&env.current_contract_address(), &seller, &seller_share);
-
-                // Cancel all other bids.
-                let market = token::Client::new(&env, &auction_data.market);
-                for b in auction_data.bids.iter() {
-                    if b.amount > 0 && b.buyer != bid.buyer {
-                        market.transfer(&env.current_contract_address(), &b.buyer, &b.amount);
-                    }
-                }
+            Some(bid) => self.finalize_at_price(env, auction_id, bid, bid.amount),
+            None => self.finalize_fills(env, auction_id, &vec![env]),
+        }
+    }
 
-                // Delete the auction.
-                delete_data::<DataKey, AuctionData>(env, &DataKey::AuctionData(seller.clone()));
-                env.events()
-                    .publish((AUCTION, symbol_short!("won")), seller);
-                true
-            }
-            None => {
-                // No winner.
-                // Transfer token back to seller.
-                let token = token::Client::new(&env, &auction_data.token);
-                token.transfer(
-                    &env.current_contract_address(),
-                    &seller,
-                    &auction_data.amount,
-                );
+    // Completes the auction charging the winner at the clearing price
+    // and refunding the difference with the winning bid amount.
+    fn finalize_at_price(&self, env: &Env, auction_id: u64, bid: &BidData, price: i128) -> bool {
+        let auction_data = load_data::<DataKey, AuctionData>(env, &DataKey::AuctionData(auction_id));
+        self.finalize_fills(
+            env,
+            auction_id,
+            &vec![
+                env,
+                FillData {
+                    buyer: bid.buyer.clone(),
+                    quantity: auction_data.amount,
+                    price,
+                },
+            ],
+        )
+    }
 
-                // Cancel all bids.
-                let market = token::Client::new(&env, &auction_data.market);
-                for bid in auction_data.bids.iter() {
-                    if bid.amount > 0 {
-                        market.transfer(&env.current_contract_address(), &bid.buyer, &bid.amount);
-                    }
-                }
+    // Completes the auction with the given fills, each buyer receiving the filled
+    // quantity for the fill price. Commission is computed per fill, bids are refunded
+    // above their fill price and unsold units are transferred back to the seller.
+    fn finalize_fills(&self, env: &Env, auction_id: u64, fills: &Vec<FillData>) -> bool {
+        let auction_data = load_data::<DataKey, AuctionData>(env, &DataKey::AuctionData(auction_id));
+        let admin_data = load_data::<DataKey, AdminData>(env, &DataKey::AdminData);
+        let token = token::Client::new(env, &auction_data.token);
+        let market = token::Client::new(env, &auction_data.market);
 
-                // Delete the auction.
-                delete_data::<DataKey, AuctionData>(env, &DataKey::AuctionData(seller.clone()));
-                env.events()
-                    .publish((AUCTION, symbol_short!("ended")), seller);
-                true
+        let mut admin_share: i128 = 0;
+        let mut seller_share: i128 = 0;
+        let mut sold: i128 = 0;
+        for fill in fills.iter() {
+            let escrow = auction_data
+                .bids
+                .iter()
+                .find(|b| b.buyer == fill.buyer)
+                .map_or(0, |b| b.amount);
+            if fill.quantity <= 0 || fill.price < 0 || fill.price > escrow {
+                panic!("Invalid clearing price.");
             }
+
+            // We have a winner, transfer token to buyer.
+            token.transfer(&env.current_contract_address(), &fill.buyer, &fill.quantity);
+            let commission = fill.price * admin_data.commission_rate / 100;
+            admin_share += commission;
+            seller_share += fill.price - commission;
+            sold += fill.quantity;
+        }
+
+        if sold > auction_data.amount {
+            panic!("Invalid clearing price.");
+        }
+
+        if !fills.is_empty() {
+            market.transfer(&env.current_contract_address(), &admin_data.admin, &admin_share);
+            market.transfer(&env.current_contract_address(), &auction_data.seller, &seller_share);
+        }
+
+        // Transfer unsold token back to seller.
+        if sold < auction_data.amount {
+            token.transfer(
+                &env.current_contract_address(),
+                &auction_data.seller,
+                &(auction_data.amount - sold),
+            );
+        }
+
+        // Bundled tokens go along with the whole lot, or back to seller.
+        match fills.len() {
+            1 if sold == auction_data.amount => {
+                self.deliver_bundle(env, &auction_data, &fills.get_unchecked(0).buyer)
+            }
+            _ => self.deliver_bundle(env, &auction_data, &auction_data.seller),
+        }
+
+        // Cancel all bids, refunding amounts above the fill prices.
+        for bid in auction_data.bids.iter() {
+            let paid = fills
+                .iter()
+                .find(|fill| fill.buyer == bid.buyer)
+                .map_or(0, |fill| fill.price);
+            if bid.amount > paid {
+                market.transfer(&env.current_contract_address(), &bid.buyer, &(bid.amount - paid));
+            }
+        }
+
+        // Delete the auction.
+        delete_auction(env, auction_id, &auction_data);
+        if fills.is_empty() {
+            env.events()
+                .publish((AUCTION, symbol_short!("ended")), auction_id);
+        } else {
+            env.events()
+                .publish((AUCTION, symbol_short!("won")), auction_id);
+        }
+        true
+    }
+
+    // Completes an immediate sale of a quantity of the lot, escrowing the price
+    // from the buyer and paying the seller minus commission.
+    fn settle_sale(
+        &self,
+        env: &Env,
+        auction_id: u64,
+        auction_data: &AuctionData,
+        buyer: &Address,
+        quantity: i128,
+        price: i128,
+    ) {
+        self.escrow_bid(env, auction_data, buyer, price, quantity);
+
+        let admin_data = load_data::<DataKey, AdminData>(env, &DataKey::AdminData);
+        let token = token::Client::new(env, &auction_data.token);
+        let market = token::Client::new(env, &auction_data.market);
+        let admin_share = price * admin_data.commission_rate / 100;
+        let seller_share = price - admin_share;
+
+        token.transfer(&env.current_contract_address(), buyer, &quantity);
+        market.transfer(&env.current_contract_address(), &admin_data.admin, &admin_share);
+        market.transfer(&env.current_contract_address(), &auction_data.seller, &seller_share);
+        env.events().publish((BID, symbol_short!("sold")), auction_id);
+    }
+
+    // Transfers the bundled tokens from the contract to the recipient.
+    fn deliver_bundle(&self, env: &Env, auction_data: &AuctionData, recipient: &Address) {
+        for lot in auction_data.bundle.iter() {
+            let token = token::Client::new(env, &lot.token);
+            token.transfer(&env.current_contract_address(), recipient, &lot.amount);
+        }
+    }
+
+    // Whether the lot can be bundled with other tokens (i.e. it is sold as a whole).
+    fn supports_bundle(&self, _auction_data: &AuctionData) -> bool {
+        true
+    }
+
+    // Whether a bid amount on the given quantity is acceptable.
+    fn accepts_bid(&self, auction_data: &AuctionData, amount: i128, _quantity: i128) -> bool {
+        amount >= minimum_bid(auction_data)
+    }
+
+    // Whether the auction parameters are valid for the behavior.
+    // Checked before the lot is escrowed, when starting and editing the auction.
+    fn accepts_parameters(&self, _auction_data: &AuctionData) -> bool {
+        true
+    }
+
+    // Transfers the lot from the seller to the contract.
+    fn escrow_lot(&self, env: &Env, auction_data: &AuctionData) {
+        let token = token::Client::new(env, &auction_data.token);
+        token.transfer(&auction_data.seller, &env.current_contract_address(), &auction_data.amount);
+    }
+
+    // Transfers the escrowed lot back to the seller.
+    fn release_lot(&self, env: &Env, auction_data: &AuctionData) {
+        let token = token::Client::new(env, &auction_data.token);
+        token.transfer(&env.current_contract_address(), &auction_data.seller, &auction_data.amount);
+    }
+
+    // Transfers the bid amount from the buyer to the contract.
+    fn escrow_bid(
+        &self,
+        env: &Env,
+        auction_data: &AuctionData,
+        buyer: &Address,
+        amount: i128,
+        _quantity: i128,
+    ) {
+        let market = token::Client::new(env, &auction_data.market);
+        market.transfer(buyer, &env.current_contract_address(), &amount);
+    }
+
+    // Transfers the escrowed bid back to the buyer.
+    fn refund_bid(&self, env: &Env, _auction_id: u64, auction_data: &AuctionData, bid: &BidData) {
+        let market = token::Client::new(env, &auction_data.market);
+        market.transfer(&env.current_contract_address(), &bid.buyer, &bid.amount);
+    }
+
+    // Anti-snipe window (in seconds) before the auction end,
+    // and whether late bids extend the auction duration.
+    fn anti_snipe(&self, env: &Env, auction_data: &AuctionData) -> (u64, bool) {
+        match &auction_data.soft_close {
+            SoftClose::Custom(soft_close) => (soft_close.window, true),
+            SoftClose::Default => (
+                load_data::<DataKey, AdminData>(env, &DataKey::AdminData).anti_snipe_time,
+                true,
+            ),
         }
     }
 
-    fn resolve(&self, env: &Env, seller: &Address) -> bool;
+    // Time (in seconds) needed past the auction duration to settle (e.g. reveal phase).
+    fn settlement_time(&self, _auction_data: &AuctionData) -> u64 {
+        0
+    }
 
-    fn calculate_price(&self, _env: &Env, seller: &Address) -> i128;
+    fn resolve(&self, env: &Env, auction_id: u64) -> bool;
+
+    fn calculate_price(&self, _env: &Env, auction_id: u64) -> i128;
 }
 
 // Using enum/match since no_std prevents the use of dynamic dispatch.
 pub enum Dispatcher {
     AscendingPriceAuction,
     DescendingPriceAuction,
+    SealedBidAuction,
+    VickreyAuction,
+    CandleAuction,
+    UniformPriceAuction,
+    DiscriminatoryPriceAuction,
+    ReverseAuction,
+    ClockAuction,
+    GradualDutchAuction,
+    VariableRateGradualDutchAuction,
+    FixedPriceAuction,
+    OfferAuction,
+    DutchEnglishAuction,
 }
 
 impl BaseAuction for Dispatcher {
-    fn resolve(&self, env: &Env, seller: &Address) -> bool {
+    fn start(&self, env: &Env, auction_id: u64, auction_data: &AuctionData) {
+        match self {
+            Dispatcher::AscendingPriceAuction => AscendingPriceAuction.start(env, auction_id, auction_data),
+            Dispatcher::DescendingPriceAuction => {
+                DescendingPriceAuction.start(env, auction_id, auction_data)
+            }
+            Dispatcher::SealedBidAuction => SealedBidAuction.start(env, auction_id, auction_data),
+            Dispatcher::VickreyAuction => VickreyAuction.start(env, auction_id, auction_data),
+            Dispatcher::CandleAuction => CandleAuction.start(env, auction_id, auction_data),
+            Dispatcher::UniformPriceAuction => UniformPriceAuction.start(env, auction_id, auction_data),
+            Dispatcher::DiscriminatoryPriceAuction => {
+                DiscriminatoryPriceAuction.start(env, auction_id, auction_data)
+            }
+            Dispatcher::ReverseAuction => ReverseAuction.start(env, auction_id, auction_data),
+            Dispatcher::ClockAuction => ClockAuction.start(env, auction_id, auction_data),
+            Dispatcher::GradualDutchAuction => GradualDutchAuction.start(env, auction_id, auction_data),
+            Dispatcher::VariableRateGradualDutchAuction => {
+                VariableRateGradualDutchAuction.start(env, auction_id, auction_data)
+            }
+            Dispatcher::FixedPriceAuction => FixedPriceAuction.start(env, auction_id, auction_data),
+            Dispatcher::OfferAuction => OfferAuction.start(env, auction_id, auction_data),
+            Dispatcher::DutchEnglishAuction => DutchEnglishAuction.start(env, auction_id, auction_data),
+        }
+    }
+
+    fn manage_bid(&self, env: &Env, auction_id: u64, buyer: &Address, amount: i128, quantity: i128) {
+        match self {
+            Dispatcher::AscendingPriceAuction => {
+                AscendingPriceAuction.manage_bid(env, auction_id, buyer, amount, quantity)
+            }
+            Dispatcher::DescendingPriceAuction => {
+                DescendingPriceAuction.manage_bid(env, auction_id, buyer, amount, quantity)
+            }
+            Dispatcher::SealedBidAuction => {
+                SealedBidAuction.manage_bid(env, auction_id, buyer, amount, quantity)
+            }
+            Dispatcher::VickreyAuction => {
+                VickreyAuction.manage_bid(env, auction_id, buyer, amount, quantity)
+            }
+            Dispatcher::CandleAuction => {
+                CandleAuction.manage_bid(env, auction_id, buyer, amount, quantity)
+            }
+            Dispatcher::UniformPriceAuction => {
+                UniformPriceAuction.manage_bid(env, auction_id, buyer, amount, quantity)
+            }
+            Dispatcher::DiscriminatoryPriceAuction => {
+                DiscriminatoryPriceAuction.manage_bid(env, auction_id, buyer, amount, quantity)
+            }
+            Dispatcher::ReverseAuction => {
+                ReverseAuction.manage_bid(env, auction_id, buyer, amount, quantity)
+            }
+            Dispatcher::ClockAuction => ClockAuction.manage_bid(env, auction_id, buyer, amount, quantity),
+            Dispatcher::GradualDutchAuction => {
+                GradualDutchAuction.manage_bid(env, auction_id, buyer, amount, quantity)
+            }
+            Dispatcher::VariableRateGradualDutchAuction => {
+                VariableRateGradualDutchAuction.manage_bid(env, auction_id, buyer, amount, quantity)
+            }
+            Dispatcher::FixedPriceAuction => {
+                FixedPriceAuction.manage_bid(env, auction_id, buyer, amount, quantity)
+            }
+            Dispatcher::OfferAuction => OfferAuction.manage_bid(env, auction_id, buyer, amount, quantity),
+            Dispatcher::DutchEnglishAuction => {
+                DutchEnglishAuction.manage_bid(env, auction_id, buyer, amount, quantity)
+            }
+        }
+    }
+
+    fn raise_bid(&self, env: &Env, auction_id: u64, buyer: &Address, amount: i128) {
+        match self {
+            Dispatcher::AscendingPriceAuction => AscendingPriceAuction.raise_bid(env, auction_id, buyer, amount),
+            Dispatcher::DescendingPriceAuction => {
+                DescendingPriceAuction.raise_bid(env, auction_id, buyer, amount)
+            }
+            Dispatcher::SealedBidAuction => SealedBidAuction.raise_bid(env, auction_id, buyer, amount),
+            Dispatcher::VickreyAuction => VickreyAuction.raise_bid(env, auction_id, buyer, amount),
+            Dispatcher::CandleAuction => CandleAuction.raise_bid(env, auction_id, buyer, amount),
+            Dispatcher::UniformPriceAuction => UniformPriceAuction.raise_bid(env, auction_id, buyer, amount),
+            Dispatcher::DiscriminatoryPriceAuction => {
+                DiscriminatoryPriceAuction.raise_bid(env, auction_id, buyer, amount)
+            }
+            Dispatcher::ReverseAuction => ReverseAuction.raise_bid(env, auction_id, buyer, amount),
+            Dispatcher::ClockAuction => ClockAuction.raise_bid(env, auction_id, buyer, amount),
+            Dispatcher::GradualDutchAuction => GradualDutchAuction.raise_bid(env, auction_id, buyer, amount),
+            Dispatcher::VariableRateGradualDutchAuction => {
+                VariableRateGradualDutchAuction.raise_bid(env, auction_id, buyer, amount)
+            }
+            Dispatcher::FixedPriceAuction => FixedPriceAuction.raise_bid(env, auction_id, buyer, amount),
+            Dispatcher::OfferAuction => OfferAuction.raise_bid(env, auction_id, buyer, amount),
+            Dispatcher::DutchEnglishAuction => DutchEnglishAuction.raise_bid(env, auction_id, buyer, amount),
+        }
+    }
+
+    fn supports_bundle(&self, auction_data: &AuctionData) -> bool {
+        match self {
+            Dispatcher::AscendingPriceAuction => {
+                AscendingPriceAuction.supports_bundle(auction_data)
+            }
+            Dispatcher::DescendingPriceAuction => {
+                DescendingPriceAuction.supports_bundle(auction_data)
+            }
+            Dispatcher::SealedBidAuction => SealedBidAuction.supports_bundle(auction_data),
+            Dispatcher::VickreyAuction => VickreyAuction.supports_bundle(auction_data),
+            Dispatcher::CandleAuction => CandleAuction.supports_bundle(auction_data),
+            Dispatcher::UniformPriceAuction => UniformPriceAuction.supports_bundle(auction_data),
+            Dispatcher::DiscriminatoryPriceAuction => {
+                DiscriminatoryPriceAuction.supports_bundle(auction_data)
+            }
+            Dispatcher::ReverseAuction => ReverseAuction.supports_bundle(auction_data),
+            Dispatcher::ClockAuction => ClockAuction.supports_bundle(auction_data),
+            Dispatcher::GradualDutchAuction => GradualDutchAuction.supports_bundle(auction_data),
+            Dispatcher::VariableRateGradualDutchAuction => {
+                VariableRateGradualDutchAuction.supports_bundle(auction_data)
+            }
+            Dispatcher::FixedPriceAuction => FixedPriceAuction.supports_bundle(auction_data),
+            Dispatcher::OfferAuction => OfferAuction.supports_bundle(auction_data),
+            Dispatcher::DutchEnglishAuction => DutchEnglishAuction.supports_bundle(auction_data),
+        }
+    }
+
+    fn accepts_parameters(&self, auction_data: &AuctionData) -> bool {
+        match self {
+            Dispatcher::AscendingPriceAuction => {
+                AscendingPriceAuction.accepts_parameters(auction_data)
+            }
+            Dispatcher::DescendingPriceAuction => {
+                DescendingPriceAuction.accepts_parameters(auction_data)
+            }
+            Dispatcher::SealedBidAuction => SealedBidAuction.accepts_parameters(auction_data),
+            Dispatcher::VickreyAuction => VickreyAuction.accepts_parameters(auction_data),
+            Dispatcher::CandleAuction => CandleAuction.accepts_parameters(auction_data),
+            Dispatcher::UniformPriceAuction => UniformPriceAuction.accepts_parameters(auction_data),
+            Dispatcher::DiscriminatoryPriceAuction => {
+                DiscriminatoryPriceAuction.accepts_parameters(auction_data)
+            }
+            Dispatcher::ReverseAuction => ReverseAuction.accepts_parameters(auction_data),
+            Dispatcher::ClockAuction => ClockAuction.accepts_parameters(auction_data),
+            Dispatcher::GradualDutchAuction => GradualDutchAuction.accepts_parameters(auction_data),
+            Dispatcher::VariableRateGradualDutchAuction => {
+                VariableRateGradualDutchAuction.accepts_parameters(auction_data)
+            }
+            Dispatcher::FixedPriceAuction => FixedPriceAuction.accepts_parameters(auction_data),
+            Dispatcher::OfferAuction => OfferAuction.accepts_parameters(auction_data),
+            Dispatcher::DutchEnglishAuction => DutchEnglishAuction.accepts_parameters(auction_data),
+        }
+    }
+
+    fn escrow_lot(&self, env: &Env, auction_data: &AuctionData) {
         match self {
-            Dispatcher::AscendingPriceAuction => AscendingPriceAuction.resolve(env, seller),
-            Dispatcher::DescendingPriceAuction => DescendingPriceAuction.resolve(env, seller),
+            Dispatcher::AscendingPriceAuction => {
+                AscendingPriceAuction.escrow_lot(env, auction_data)
+            }
+            Dispatcher::DescendingPriceAuction => {
+                DescendingPriceAuction.escrow_lot(env, auction_data)
+            }
+            Dispatcher::SealedBidAuction => SealedBidAuction.escrow_lot(env, auction_data),
+            Dispatcher::VickreyAuction => VickreyAuction.escrow_lot(env, auction_data),
+            Dispatcher::CandleAuction => CandleAuction.escrow_lot(env, auction_data),
+            Dispatcher::UniformPriceAuction => UniformPriceAuction.escrow_lot(env, auction_data),
+            Dispatcher::DiscriminatoryPriceAuction => {
+                DiscriminatoryPriceAuction.escrow_lot(env, auction_data)
+            }
+            Dispatcher::ReverseAuction => ReverseAuction.escrow_lot(env, auction_data),
+            Dispatcher::ClockAuction => ClockAuction.escrow_lot(env, auction_data),
+            Dispatcher::GradualDutchAuction => GradualDutchAuction.escrow_lot(env, auction_data),
+            Dispatcher::VariableRateGradualDutchAuction => {
+                VariableRateGradualDutchAuction.escrow_lot(env, auction_data)
+            }
+            Dispatcher::FixedPriceAuction => FixedPriceAuction.escrow_lot(env, auction_data),
+            Dispatcher::OfferAuction => OfferAuction.escrow_lot(env, auction_data),
+            Dispatcher::DutchEnglishAuction => DutchEnglishAuction.escrow_lot(env, auction_data),
         }
     }
 
-    fn calculate_price(&self, env: &Env, seller: &Address) -> i128 {
+    fn release_lot(&self, env: &Env, auction_data: &AuctionData) {
         match self {
-            Dispatcher::AscendingPriceAuction => AscendingPriceAuction.calculate_price(env, seller),
+            Dispatcher::AscendingPriceAuction => {
+                AscendingPriceAuction.release_lot(env, auction_data)
+            }
             Dispatcher::DescendingPriceAuction => {
-                DescendingPriceAuction.calculate_price(env, seller)
+                DescendingPriceAuction.release_lot(env, auction_data)
+            }
+            Dispatcher::SealedBidAuction => SealedBidAuction.release_lot(env, auction_data),
+            Dispatcher::VickreyAuction => VickreyAuction.release_lot(env, auction_data),
+            Dispatcher::CandleAuction => CandleAuction.release_lot(env, auction_data),
+            Dispatcher::UniformPriceAuction => UniformPriceAuction.release_lot(env, auction_data),
+            Dispatcher::DiscriminatoryPriceAuction => {
+                DiscriminatoryPriceAuction.release_lot(env, auction_data)
+            }
+            Dispatcher::ReverseAuction => ReverseAuction.release_lot(env, auction_data),
+            Dispatcher::ClockAuction => ClockAuction.release_lot(env, auction_data),
+            Dispatcher::GradualDutchAuction => GradualDutchAuction.release_lot(env, auction_data),
+            Dispatcher::VariableRateGradualDutchAuction => {
+                VariableRateGradualDutchAuction.release_lot(env, auction_data)
             }
+            Dispatcher::FixedPriceAuction => FixedPriceAuction.release_lot(env, auction_data),
+            Dispatcher::OfferAuction => OfferAuction.release_lot(env, auction_data),
+            Dispatcher::DutchEnglishAuction => DutchEnglishAuction.release_lot(env, auction_data),
+        }
+    }
+
+    fn anti_snipe(&self, env: &Env, auction_data: &AuctionData) -> (u64, bool) {
+        match self {
+            Dispatcher::AscendingPriceAuction => {
+                AscendingPriceAuction.anti_snipe(env, auction_data)
+            }
+            Dispatcher::DescendingPriceAuction => {
+                DescendingPriceAuction.anti_snipe(env, auction_data)
+            }
+            Dispatcher::SealedBidAuction => SealedBidAuction.anti_snipe(env, auction_data),
+            Dispatcher::VickreyAuction => VickreyAuction.anti_snipe(env, auction_data),
+            Dispatcher::CandleAuction => CandleAuction.anti_snipe(env, auction_data),
+            Dispatcher::UniformPriceAuction => UniformPriceAuction.anti_snipe(env, auction_data),
+            Dispatcher::DiscriminatoryPriceAuction => {
+                DiscriminatoryPriceAuction.anti_snipe(env, auction_data)
+            }
+            Dispatcher::ReverseAuction => ReverseAuction.anti_snipe(env, auction_data),
+            Dispatcher::ClockAuction => ClockAuction.anti_snipe(env, auction_data),
+            Dispatcher::GradualDutchAuction => GradualDutchAuction.anti_snipe(env, auction_data),
+            Dispatcher::VariableRateGradualDutchAuction => {
+                VariableRateGradualDutchAuction.anti_snipe(env, auction_data)
+            }
+            Dispatcher::FixedPriceAuction => FixedPriceAuction.anti_snipe(env, auction_data),
+            Dispatcher::OfferAuction => OfferAuction.anti_snipe(env, auction_data),
+            Dispatcher::DutchEnglishAuction => DutchEnglishAuction.anti_snipe(env, auction_data),
+        }
+    }
+
+    fn settlement_time(&self, auction_data: &AuctionData) -> u64 {
+        match self {
+            Dispatcher::AscendingPriceAuction => {
+                AscendingPriceAuction.settlement_time(auction_data)
+            }
+            Dispatcher::DescendingPriceAuction => {
+                DescendingPriceAuction.settlement_time(auction_data)
+            }
+            Dispatcher::SealedBidAuction => SealedBidAuction.settlement_time(auction_data),
+            Dispatcher::VickreyAuction => VickreyAuction.settlement_time(auction_data),
+            Dispatcher::CandleAuction => CandleAuction.settlement_time(auction_data),
+            Dispatcher::UniformPriceAuction => UniformPriceAuction.settlement_time(auction_data),
+            Dispatcher::DiscriminatoryPriceAuction => {
+                DiscriminatoryPriceAuction.settlement_time(auction_data)
+            }
+            Dispatcher::ReverseAuction => ReverseAuction.settlement_time(auction_data),
+            Dispatcher::ClockAuction => ClockAuction.settlement_time(auction_data),
+            Dispatcher::GradualDutchAuction => GradualDutchAuction.settlement_time(auction_data),
+            Dispatcher::VariableRateGradualDutchAuction => {
+                VariableRateGradualDutchAuction.settlement_time(auction_data)
+            }
+            Dispatcher::FixedPriceAuction => FixedPriceAuction.settlement_time(auction_data),
+            Dispatcher::OfferAuction => OfferAuction.settlement_time(auction_data),
+            Dispatcher::DutchEnglishAuction => DutchEnglishAuction.settlement_time(auction_data),
+        }
+    }
+
+    fn cancel(&self, env: &Env, auction_id: u64) {
+        match self {
+            Dispatcher::AscendingPriceAuction => AscendingPriceAuction.cancel(env, auction_id),
+            Dispatcher::DescendingPriceAuction => DescendingPriceAuction.cancel(env, auction_id),
+            Dispatcher::SealedBidAuction => SealedBidAuction.cancel(env, auction_id),
+            Dispatcher::VickreyAuction => VickreyAuction.cancel(env, auction_id),
+            Dispatcher::CandleAuction => CandleAuction.cancel(env, auction_id),
+            Dispatcher::UniformPriceAuction => UniformPriceAuction.cancel(env, auction_id),
+            Dispatcher::DiscriminatoryPriceAuction => {
+                DiscriminatoryPriceAuction.cancel(env, auction_id)
+            }
+            Dispatcher::ReverseAuction => ReverseAuction.cancel(env, auction_id),
+            Dispatcher::ClockAuction => ClockAuction.cancel(env, auction_id),
+            Dispatcher::GradualDutchAuction => GradualDutchAuction.cancel(env, auction_id),
+            Dispatcher::VariableRateGradualDutchAuction => {
+                VariableRateGradualDutchAuction.cancel(env, auction_id)
+            }
+            Dispatcher::FixedPriceAuction => FixedPriceAuction.cancel(env, auction_id),
+            Dispatcher::OfferAuction => OfferAuction.cancel(env, auction_id),
+            Dispatcher::DutchEnglishAuction => DutchEnglishAuction.cancel(env, auction_id),
+        }
+    }
+
+    fn resolve(&self, env: &Env, auction_id: u64) -> bool {
+        match self {
+            Dispatcher::AscendingPriceAuction => AscendingPriceAuction.resolve(env, auction_id),
+            Dispatcher::DescendingPriceAuction => DescendingPriceAuction.resolve(env, auction_id),
+            Dispatcher::SealedBidAuction => SealedBidAuction.resolve(env, auction_id),
+            Dispatcher::VickreyAuction => VickreyAuction.resolve(env, auction_id),
+            Dispatcher::CandleAuction => CandleAuction.resolve(env, auction_id),
+            Dispatcher::UniformPriceAuction => UniformPriceAuction.resolve(env, auction_id),
+            Dispatcher::DiscriminatoryPriceAuction => {
+                DiscriminatoryPriceAuction.resolve(env, auction_id)
+            }
+            Dispatcher::ReverseAuction => ReverseAuction.resolve(env, auction_id),
+            Dispatcher::ClockAuction => ClockAuction.resolve(env, auction_id),
+            Dispatcher::GradualDutchAuction => GradualDutchAuction.resolve(env, auction_id),
+            Dispatcher::VariableRateGradualDutchAuction => {
+                VariableRateGradualDutchAuction.resolve(env, auction_id)
+            }
+            Dispatcher::FixedPriceAuction => FixedPriceAuction.resolve(env, auction_id),
+            Dispatcher::OfferAuction => OfferAuction.resolve(env, auction_id),
+            Dispatcher::DutchEnglishAuction => DutchEnglishAuction.resolve(env, auction_id),
+        }
+    }
+
+    fn calculate_price(&self, env: &Env, auction_id: u64) -> i128 {
+        match self {
+            Dispatcher::AscendingPriceAuction => AscendingPriceAuction.calculate_price(env, auction_id),
+            Dispatcher::DescendingPriceAuction => {
+                DescendingPriceAuction.calculate_price(env, auction_id)
+            }
+            Dispatcher::SealedBidAuction => SealedBidAuction.calculate_price(env, auction_id),
+            Dispatcher::VickreyAuction => VickreyAuction.calculate_price(env, auction_id),
+            Dispatcher::CandleAuction => CandleAuction.calculate_price(env, auction_id),
+            Dispatcher::UniformPriceAuction => UniformPriceAuction.calculate_price(env, auction_id),
+            Dispatcher::DiscriminatoryPriceAuction => {
+                DiscriminatoryPriceAuction.calculate_price(env, auction_id)
+            }
+            Dispatcher::ReverseAuction => ReverseAuction.calculate_price(env, auction_id),
+            Dispatcher::ClockAuction => ClockAuction.calculate_price(env, auction_id),
+            Dispatcher::GradualDutchAuction => GradualDutchAuction.calculate_price(env, auction_id),
+            Dispatcher::VariableRateGradualDutchAuction => {
+                VariableRateGradualDutchAuction.calculate_price(env, auction_id)
+            }
+            Dispatcher::FixedPriceAuction => FixedPriceAuction.calculate_price(env, auction_id),
+            Dispatcher::OfferAuction => OfferAuction.calculate_price(env, auction_id),
+            Dispatcher::DutchEnglishAuction => DutchEnglishAuction.calculate_price(env, auction_id),
         }
     }
 }
 
 #[macro_export]
 macro_rules! dispatcher {
-    ($condition:expr) => {
-        if $condition {
-            Dispatcher::DescendingPriceAuction
-        } else {
-            Dispatcher::AscendingPriceAuction
+    ($behavior:expr) => {
+        match $behavior {
+            $crate::types::AuctionBehavior::AscendingPrice => Dispatcher::AscendingPriceAuction,
+            $crate::types::AuctionBehavior::DescendingPrice
+            | $crate::types::AuctionBehavior::DescendingPriceSchedule(_) => {
+                Dispatcher::DescendingPriceAuction
+            }
+            $crate::types::AuctionBehavior::SealedBid(_) => Dispatcher::SealedBidAuction,
+            $crate::types::AuctionBehavior::Vickrey(_) => Dispatcher::VickreyAuction,
+            $crate::types::AuctionBehavior::Candle(_) => Dispatcher::CandleAuction,
+            $crate::types::AuctionBehavior::UniformPrice => Dispatcher::UniformPriceAuction,
+            $crate::types::AuctionBehavior::DiscriminatoryPrice => {
+                Dispatcher::DiscriminatoryPriceAuction
+            }
+            $crate::types::AuctionBehavior::Reverse => Dispatcher::ReverseAuction,
+            $crate::types::AuctionBehavior::Clock(_) => Dispatcher::ClockAuction,
+            $crate::types::AuctionBehavior::GradualDutch(_) => Dispatcher::GradualDutchAuction,
+            $crate::types::AuctionBehavior::VariableRateGradualDutch(_) => {
+                Dispatcher::VariableRateGradualDutchAuction
+            }
+            $crate::types::AuctionBehavior::FixedPrice(_) => Dispatcher::FixedPriceAuction,
+            $crate::types::AuctionBehavior::Offer => Dispatcher::OfferAuction,
+            $crate::types::AuctionBehavior::DutchEnglish(_) => Dispatcher::DutchEnglishAuction,
         }
     };
 }
@@ -6,40 +6,196 @@
     MIT License
 */
 
-use soroban_sdk::{Address, Env};
-use crate::{storage::*, types::{AuctionData, DataKey}};
+use core::cmp::Reverse;
+use soroban_sdk::{token, vec, Address, Env};
+use super::{
+    behavior::{best_bid, cancel_auction, ledger_times, BaseAuction},
+    bid_increment::increment_over,
+};
+use crate::{storage::*, types::{AuctionData, BidData, DataKey, ProxyBidData, ProxyMaximumData}};
 
 pub struct AscendingPriceAuction;
 
 // AscendingPriceAuction (aka English Auction).
-impl super::behavior::BaseAuction for AscendingPriceAuction {
-    fn resolve(&self, env: &Env, seller: &Address) -> bool {
-        let auction_data = load_data::<DataKey, AuctionData>(env, &DataKey::AuctionData(seller.clone()));
+// Proxy bids escrow a maximum amount and are raised automatically
+// only as much as needed to lead (outbidding competing bids by the bid increment).
+impl BaseAuction for AscendingPriceAuction {
+    fn refund_bid(&self, env: &Env, auction_id: u64, auction_data: &AuctionData, bid: &BidData) {
+        // Proxy bids have their maximum escrowed above the bid amount.
+        let amount = bid.amount
+            + remove_proxy_maximum(env, auction_id, &bid.buyer).map_or(0, |maximum| {
+                maximum
+                    - auction_data
+                        .bids
+                        .iter()
+                        .find(|b| b.buyer == bid.buyer)
+                        .map_or(maximum, |b| b.amount)
+            });
+        let market = token::Client::new(env, &auction_data.market);
+        market.transfer(&env.current_contract_address(), &bid.buyer, &amount);
+    }
+
+    fn cancel(&self, env: &Env, auction_id: u64) {
+        // Refund the escrowed maximums above the proxy bids.
+        let auction_data = load_data::<DataKey, AuctionData>(env, &DataKey::AuctionData(auction_id));
+        settle_proxy_bids(env, auction_id, &auction_data);
+        cancel_auction(self, env, auction_id);
+    }
+
+    fn resolve(&self, env: &Env, auction_id: u64) -> bool {
+        let mut auction_data = load_data::<DataKey, AuctionData>(env, &DataKey::AuctionData(auction_id));
+
+        // Raise proxy bids against competing bids.
+        if update_proxy_bids(env, auction_id, &mut auction_data) {
+            save_data::<DataKey, AuctionData>(env, &DataKey::AuctionData(auction_id), &auction_data);
+        }
 
         // Retrieve the highest bid.
-        if let Some(bid) = auction_data.bids.iter().max_by_key(|bid| bid.amount) {
+        if let Some(bid) = best_bid(&auction_data) {
             // Check that the reserve is met and
             // either the auction time has expired or the ask price is met.
             if bid.amount >= auction_data.reserve_price
                 && (auction_data.start_time + auction_data.duration < env.ledger().timestamp()
                     || (auction_data.ask_price > auction_data.reserve_price
                         && bid.amount >= auction_data.ask_price)) {
-                return self.finalize(env, seller, Some(&bid));
+                settle_proxy_bids(env, auction_id, &auction_data);
+                return self.finalize(env, auction_id, Some(&bid));
             }
         }
         else {
             // Auction has expired.
             if auction_data.start_time + auction_data.duration < env.ledger().timestamp() {
-                return self.finalize(env, seller, None);    
+                return self.finalize(env, auction_id, None);    
             }
         }
         false
     }
 
-    fn calculate_price(&self, env: &Env, seller: &Address) -> i128 {
-        load_data::<DataKey, AuctionData>(env, &DataKey::AuctionData(seller.clone())).reserve_price
+    fn calculate_price(&self, env: &Env, auction_id: u64) -> i128 {
+        load_data::<DataKey, AuctionData>(env, &DataKey::AuctionData(auction_id)).reserve_price
+    }
+}
+
+impl AscendingPriceAuction {
+    pub fn place_proxy_bid(&self, env: &Env, auction_id: u64, buyer: &Address, maximum: i128) {
+        // First check that the auction is resolved.
+        if self.resolve(env, auction_id) {
+            return;
+        }
+
+        let auction_data = load_data::<DataKey, AuctionData>(env, &DataKey::AuctionData(auction_id));
+        let key = DataKey::ProxyBidData(auction_id);
+        let mut proxy_data = load_data_or_else::<DataKey, ProxyBidData, _, _>(env, &key, |opt| {
+            opt.unwrap_or_else(|| ProxyBidData {
+                maximums: vec![env],
+            })
+        });
+        proxy_data.maximums.push_back(ProxyMaximumData {
+            buyer: buyer.clone(),
+            maximum,
+        });
+        save_data::<DataKey, ProxyBidData>(env, &key, &proxy_data);
+
+        // Keep maximums alive as long as the auction.
+        let lifetime = ledger_times::convert_seconds_to_ledgers(
+            auction_data.start_time + auction_data.duration - env.ledger().timestamp()
+                + ledger_times::EXPIRATION_BUFFER,
+        );
+        bump_data::<DataKey, ProxyBidData>(env, &key, lifetime, lifetime);
+
+        // Escrow the maximum, the bid is then lowered to the amount needed.
+        self.manage_bid(env, auction_id, buyer, maximum, auction_data.amount);
+    }
+}
+
+// Sets proxy bids to the amount needed for the leading bid to outbid the runner-up
+// (capped at its maximum), other proxy bids are raised to their maximum.
+// Returns false if there are no proxy bids.
+fn update_proxy_bids(env: &Env, auction_id: u64, auction_data: &mut AuctionData) -> bool {
+    let key = DataKey::ProxyBidData(auction_id);
+    let proxy_data = match load_data_or_else::<DataKey, ProxyBidData, _, _>(env, &key, |opt| opt) {
+        Some(proxy_data) => proxy_data,
+        None => return false,
+    };
+
+    let cap = |bid: &BidData| {
+        proxy_data
+            .maximums
+            .iter()
+            .find(|p| p.buyer == bid.buyer)
+            .map_or(bid.amount, |p| p.maximum)
+    };
+    let leader = auction_data
+        .bids
+        .iter()
+        .enumerate()
+        .max_by_key(|(_, bid)| (cap(bid), Reverse(bid.sequence)))
+        .map(|(index, _)| index);
+    let runner_up = auction_data
+        .bids
+        .iter()
+        .enumerate()
+        .filter(|(index, _)| Some(*index) != leader)
+        .map(|(_, bid)| cap(&bid))
+        .max();
+
+    for (index, mut bid) in auction_data.bids.clone().iter().enumerate() {
+        if let Some(proxy) = proxy_data.maximums.iter().find(|p| p.buyer == bid.buyer) {
+            bid.amount = if Some(index) == leader {
+                runner_up
+                    .map_or(auction_data.reserve_price, |runner_up| {
+                        runner_up + increment_over(&auction_data.bid_increment, runner_up).max(1)
+                    })
+                    .max(auction_data.reserve_price)
+                    .min(proxy.maximum)
+            } else {
+                proxy.maximum
+            };
+            auction_data.bids.set(index as u32, bid);
+        }
     }
+    true
 }
 
+// Refunds the escrowed maximums above the proxy bids and removes the maximums.
+fn settle_proxy_bids(env: &Env, auction_id: u64, auction_data: &AuctionData) {
+    let key = DataKey::ProxyBidData(auction_id);
+    if let Some(proxy_data) = load_data_or_else::<DataKey, ProxyBidData, _, _>(env, &key, |opt| opt) {
+        let market = token::Client::new(env, &auction_data.market);
+        for proxy in proxy_data.maximums.iter() {
+            if let Some(bid) = auction_data.bids.iter().find(|b| b.buyer == proxy.buyer) {
+                if proxy.maximum > bid.amount {
+                    market.transfer(
+                        &env.current_contract_address(),
+                        &proxy.buyer,
+                        &(proxy.maximum - bid.amount),
+                    );
+                }
+            }
+        }
+        delete_data::<DataKey, ProxyBidData>(env, &key);
+    }
+}
 
+pub fn has_proxy_bid(env: &Env, auction_id: u64, buyer: &Address) -> bool {
+    load_data_or_else::<DataKey, ProxyBidData, _, _>(
+        env,
+        &DataKey::ProxyBidData(auction_id),
+        |opt| opt,
+    )
+    .is_some_and(|proxy_data| proxy_data.maximums.iter().any(|p| p.buyer == *buyer))
+}
 
+fn remove_proxy_maximum(env: &Env, auction_id: u64, buyer: &Address) -> Option<i128> {
+    let key = DataKey::ProxyBidData(auction_id);
+    let mut proxy_data = load_data_or_else::<DataKey, ProxyBidData, _, _>(env, &key, |opt| opt)?;
+    let index = proxy_data.maximums.iter().position(|p| p.buyer == *buyer)? as u32;
+    let maximum = proxy_data.maximums.get_unchecked(index).maximum;
+    proxy_data.maximums.remove(index);
+    if proxy_data.maximums.is_empty() {
+        delete_data::<DataKey, ProxyBidData>(env, &key);
+    } else {
+        save_data::<DataKey, ProxyBidData>(env, &key, &proxy_data);
+    }
+    Some(maximum)
+}
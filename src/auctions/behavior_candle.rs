@@ -0,0 +1,70 @@
+/*
+    Date: 2023
+    Author: Fred Kyung-jin Rezeau <fred@litemint.com>
+    Copyright (c) 2023 Litemint LLC
+
+    MIT License
+*/
+
+use core::cmp::Reverse;
+use soroban_sdk::{symbol_short, Env};
+use super::behavior::{BaseAuction, AUCTION};
+use crate::{storage::*, types::{AdminData, AuctionBehavior, AuctionData, DataKey}};
+
+pub struct CandleAuction;
+
+// CandleAuction (aka Random-Close Auction).
+// The actual end time is drawn with the Soroban PRNG within the candle window
+// once the auction duration has elapsed, bids placed after it do not count.
+// Bids are final during the candle window and do not extend the duration.
+// The draw happens in the resolving transaction, so a caller could retry it (e.g. from a
+// contract reverting unfavourable cutoffs) until it picks their bid. Resolving therefore
+// requires admin authorization, and the admin is trusted not to grind the draw.
+impl BaseAuction for CandleAuction {
+    fn anti_snipe(&self, _env: &Env, auction_data: &AuctionData) -> (u64, bool) {
+        (candle_duration(auction_data), false)
+    }
+
+    fn resolve(&self, env: &Env, auction_id: u64) -> bool {
+        let auction_data = load_data::<DataKey, AuctionData>(env, &DataKey::AuctionData(auction_id));
+
+        let end_time = auction_data.start_time + auction_data.duration;
+        if end_time >= env.ledger().timestamp() {
+            return false;
+        }
+
+        // Only the admin may draw the cutoff.
+        load_data::<DataKey, AdminData>(env, &DataKey::AdminData).admin.require_auth();
+
+        // Blow out the candle.
+        let cutoff = env
+            .prng()
+            .u64_in_range(end_time - candle_duration(&auction_data)..=end_time);
+        env.events()
+            .publish((AUCTION, symbol_short!("cutoff")), (auction_id, cutoff));
+
+        // Retrieve the highest bid placed before the cutoff.
+        match auction_data
+            .bids
+            .iter()
+            .filter(|bid| bid.timestamp <= cutoff)
+            .max_by_key(|bid| (bid.amount, Reverse(bid.sequence)))
+        {
+            Some(bid) if bid.amount >= auction_data.reserve_price => {
+                self.finalize(env, auction_id, Some(&bid))
+            }
+            _ => self.finalize(env, auction_id, None),
+        }
+    }
+
+    fn calculate_price(&self, env: &Env, auction_id: u64) -> i128 {
+        load_data::<DataKey, AuctionData>(env, &DataKey::AuctionData(auction_id)).reserve_price
+    }
+}
+
+fn candle_duration(auction_data: &AuctionData) -> u64 {
+    match auction_data.behavior {
+        AuctionBehavior::Candle(candle_duration) => candle_duration.min(auction_data.duration),
+        _ => 0,
+    }
+}
@@ -0,0 +1,172 @@
+/*
+    Date: 2023
+    Author: Fred Kyung-jin Rezeau <fred@litemint.com>
+    Copyright (c) 2023 Litemint LLC
+
+    MIT License
+*/
+
+use core::cmp::Reverse;
+use soroban_sdk::{symbol_short, Address, Env};
+use super::behavior::{next_sequence, BaseAuction, BID};
+use crate::{storage::*, types::{AuctionBehavior, AuctionData, BidData, ClockData, DataKey}};
+
+pub struct ClockAuction;
+
+// ClockAuction (aka Japanese Auction).
+// The clock price starts at reserve_price and rises by the increment every round.
+// Bidders enter during the first round and must stay in every round by placing
+// a bid at the current clock price (topping up their escrow), or they are dropped.
+// The last remaining bidder wins at the clock price of the round it stayed in alone.
+// If all remaining bidders drop in the same round (or the auction expires), the
+// earliest bidder to have stayed in the last contested round wins at that price.
+impl BaseAuction for ClockAuction {
+    fn accepts_parameters(&self, auction_data: &AuctionData) -> bool {
+        let clock_data = clock_data(auction_data);
+        clock_data.increment > 0 && clock_data.round_duration > 0
+    }
+
+    fn manage_bid(&self, env: &Env, auction_id: u64, buyer: &Address, amount: i128, quantity: i128) {
+        // First check that the auction is resolved.
+        let resolved = self.resolve(env, auction_id);
+        if resolved {
+            return;
+        }
+
+        let mut auction_data = load_data::<DataKey, AuctionData>(env, &DataKey::AuctionData(auction_id));
+        let round = current_round(env, &auction_data);
+        let index = auction_data.bids.iter().position(|b| b.buyer == *buyer);
+
+        if amount == 0 {
+            // Drop out of the auction, keeping the last round stayed in for pricing.
+            let index = index.filter(|index| auction_data.bids.get_unchecked(*index as u32).amount > 0);
+            if let Some(index) = index {
+                let mut bid = auction_data.bids.get_unchecked(index as u32);
+                self.refund_bid(env, auction_id, &auction_data, &bid);
+                bid.amount = 0;
+                auction_data.bids.set(index as u32, bid);
+                env.events()
+                    .publish((BID, symbol_short!("deleted")), auction_id);
+            } else {
+                panic!("No bid to cancel.");
+            }
+        } else if amount > 0 && amount >= clock_price(&auction_data, round) {
+            match index {
+                Some(index) => {
+                    // Stay in for the current round, bidders missing a round
+                    // (or having dropped out) are out for good.
+                    let mut bid = auction_data.bids.get_unchecked(index as u32);
+                    let last_round = bid_round(&auction_data, &bid);
+                    if bid.amount == 0 || last_round + 1 < round {
+                        panic!("Not allowed to place new bid.");
+                    }
+
+                    if amount > bid.amount {
+                        self.escrow_bid(env, &auction_data, buyer, amount - bid.amount, quantity);
+                        bid.amount = amount;
+                    }
+                    bid.timestamp = env.ledger().timestamp();
+                    bid.sequence = next_sequence(&mut auction_data);
+                    auction_data.bids.set(index as u32, bid);
+                    env.events().publish((BID, symbol_short!("stayed")), auction_id);
+                }
+                None => {
+                    // Bidders can only enter during the first round.
+                    if round > 0 {
+                        panic!("Not allowed to place new bid.");
+                    }
+
+                    self.escrow_bid(env, &auction_data, buyer, amount, quantity);
+                    let sequence = next_sequence(&mut auction_data);
+                    auction_data.bids.push_back(BidData {
+                        buyer: buyer.clone(),
+                        amount,
+                        quantity,
+                        sniper: false,
+                        timestamp: env.ledger().timestamp(),
+                        placed_at: env.ledger().timestamp(),
+                        sequence,
+                    });
+                    env.events().publish((BID, symbol_short!("added")), auction_id);
+                }
+            }
+        } else {
+            panic!("Invalid bid amount.");
+        }
+
+        save_data::<DataKey, AuctionData>(env, &DataKey::AuctionData(auction_id), &auction_data);
+    }
+
+    fn resolve(&self, env: &Env, auction_id: u64) -> bool {
+        let auction_data = load_data::<DataKey, AuctionData>(env, &DataKey::AuctionData(auction_id));
+        let expired = auction_data.start_time + auction_data.duration < env.ledger().timestamp();
+
+        // Retrieve the bidder who stayed in the latest round (earliest on ties),
+        // and the latest round any other bidder stayed in (including drop-outs).
+        let leader = auction_data
+            .bids
+            .iter()
+            .filter(|b| b.amount > 0)
+            .max_by_key(|b| {
+                (bid_round(&auction_data, b), Reverse(b.timestamp), Reverse(b.sequence))
+            });
+
+        match leader {
+            Some(leader) => {
+                let runner_up = auction_data
+                    .bids
+                    .iter()
+                    .filter(|b| b.buyer != leader.buyer)
+                    .map(|b| bid_round(&auction_data, &b))
+                    .max();
+
+                // The auction is decided once the round following the runner-up
+                // dropout is completed (or the auction has expired).
+                let decisive_round = runner_up.map_or(0, |round| round + 1);
+                if decisive_round >= current_round(env, &auction_data) && !expired {
+                    return false;
+                }
+
+                let price = match runner_up {
+                    // Tie, all remaining bidders dropped in the same round.
+                    Some(round) if round == bid_round(&auction_data, &leader) => {
+                        clock_price(&auction_data, round)
+                    }
+                    _ => clock_price(&auction_data, decisive_round),
+                };
+                self.finalize_at_price(env, auction_id, &leader, price)
+            }
+            None => {
+                // Auction has expired.
+                if expired {
+                    return self.finalize(env, auction_id, None);
+                }
+                false
+            }
+        }
+    }
+
+    fn calculate_price(&self, env: &Env, auction_id: u64) -> i128 {
+        let auction_data = load_data::<DataKey, AuctionData>(env, &DataKey::AuctionData(auction_id));
+        clock_price(&auction_data, current_round(env, &auction_data))
+    }
+}
+
+fn clock_data(auction_data: &AuctionData) -> ClockData {
+    match &auction_data.behavior {
+        AuctionBehavior::Clock(clock_data) => clock_data.clone(),
+        _ => panic!("Invalid parameters."),
+    }
+}
+
+fn current_round(env: &Env, auction_data: &AuctionData) -> u64 {
+    (env.ledger().timestamp() - auction_data.start_time) / clock_data(auction_data).round_duration
+}
+
+fn bid_round(auction_data: &AuctionData, bid: &BidData) -> u64 {
+    (bid.timestamp - auction_data.start_time) / clock_data(auction_data).round_duration
+}
+
+fn clock_price(auction_data: &AuctionData, round: u64) -> i128 {
+    auction_data.reserve_price + clock_data(auction_data).increment * round as i128
+}
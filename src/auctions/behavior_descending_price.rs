@@ -6,34 +6,54 @@
     MIT License
 */
 
-use soroban_sdk::{Address, Env};
-use crate::{storage::*, types::{AuctionData, DataKey}};
+use soroban_sdk::Env;
+use super::behavior::best_bid;
+use crate::{storage::*, types::{AuctionBehavior, AuctionData, DataKey, DiscountData, PriceScheduleData}};
 
 pub struct DescendingPriceAuction;
 
 // DescendingPriceAuction (aka Dutch Auction).
+// The price is discounted from ask_price at a linear or compound rate, or follows
+// a custom schedule of (time offset, price) breakpoints with step or linear interpolation.
 impl super::behavior::BaseAuction for DescendingPriceAuction {
-    fn resolve(&self, env: &Env, seller: &Address) -> bool {
-        let auction_data = load_data::<DataKey, AuctionData>(env, &DataKey::AuctionData(seller.clone()));
+    fn resolve(&self, env: &Env, auction_id: u64) -> bool {
+        let auction_data = load_data::<DataKey, AuctionData>(env, &DataKey::AuctionData(auction_id));
 
         // Auction has expired.
         if auction_data.start_time + auction_data.duration < env.ledger().timestamp() {
             // Finalize with no winner.
-            self.finalize(env, seller, None)
+            self.finalize(env, auction_id, None)
         }
         else {
-            if let Some(bid) = auction_data.bids.iter().max_by_key(|bid| bid.amount) {
+            if let Some(bid) = best_bid(&auction_data) {
                 // Discounted price is met, complete the auction with the winning bid.
-                if bid.amount >= self.calculate_price(env, seller) {
-                    return self.finalize(env, seller, Some(&bid));
+                if bid.amount >= self.calculate_price(env, auction_id) {
+                    return self.finalize(env, auction_id, Some(&bid));
                 }
             }
             false
         } 
     }
 
-    fn calculate_price(&self, env: &Env, seller: &Address) -> i128 {
-        let auction_data = load_data::<DataKey, AuctionData>(env, &DataKey::AuctionData(seller.clone()));
+    fn accepts_parameters(&self, auction_data: &AuctionData) -> bool {
+        match &auction_data.behavior {
+            AuctionBehavior::DescendingPriceSchedule(schedule) => is_valid_schedule(schedule),
+            _ => is_valid_discount(&DiscountData {
+                discount_percent: auction_data.discount_percent,
+                discount_frequency: auction_data.discount_frequency,
+                compounded_discount: auction_data.compounded_discount,
+            }),
+        }
+    }
+
+    fn calculate_price(&self, env: &Env, auction_id: u64) -> i128 {
+        let auction_data = load_data::<DataKey, AuctionData>(env, &DataKey::AuctionData(auction_id));
+
+        // Custom price schedule.
+        if let AuctionBehavior::DescendingPriceSchedule(schedule) = &auction_data.behavior {
+            let elapsed = env.ledger().timestamp() - auction_data.start_time;
+            return scheduled_price(schedule, elapsed).max(auction_data.reserve_price);
+        }
 
         // Sanity checks.
         if auction_data.discount_percent == 0 || auction_data.discount_frequency == 0 {
@@ -58,3 +78,39 @@ impl super::behavior::BaseAuction for DescendingPriceAuction {
         .max(auction_data.reserve_price) // Ensure price does not fall below reserve.
     }
 }
+
+pub fn is_valid_discount(discount: &DiscountData) -> bool {
+    discount.discount_percent > 0
+        && discount.discount_percent <= 100
+        && discount.discount_frequency > 0
+}
+
+pub fn is_valid_schedule(schedule: &PriceScheduleData) -> bool {
+    match schedule.breakpoints.first() {
+        Some(first) if first.offset == 0 => schedule
+            .breakpoints
+            .iter()
+            .zip(schedule.breakpoints.iter().skip(1))
+            .all(|(a, b)| a.offset < b.offset && a.price >= b.price),
+        _ => false,
+    }
+}
+
+// Price at the elapsed time, holding the last breakpoint price
+// and interpolating linearly between breakpoints if required.
+pub fn scheduled_price(schedule: &PriceScheduleData, elapsed: u64) -> i128 {
+    let index = schedule
+        .breakpoints
+        .iter()
+        .rposition(|b| b.offset <= elapsed)
+        .unwrap_or_else(|| panic!("Invalid parameters.")) as u32;
+    let from = schedule.breakpoints.get_unchecked(index);
+    match schedule.breakpoints.get(index + 1) {
+        Some(to) if schedule.linear => {
+            from.price
+                - (from.price - to.price) * (elapsed - from.offset) as i128
+                    / (to.offset - from.offset) as i128
+        }
+        _ => from.price,
+    }
+}
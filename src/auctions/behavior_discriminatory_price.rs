@@ -0,0 +1,43 @@
+/*
+    Date: 2023
+    Author: Fred Kyung-jin Rezeau <fred@litemint.com>
+    Copyright (c) 2023 Litemint LLC
+
+    MIT License
+*/
+
+use soroban_sdk::Env;
+use super::behavior::BaseAuction;
+use super::behavior_uniform_price::allocate_units;
+use crate::{storage::*, types::{AuctionData, DataKey}};
+
+pub struct DiscriminatoryPriceAuction;
+
+// DiscriminatoryPriceAuction (aka Multi-Unit Pay-As-Bid Auction).
+// Bids name a quantity and a unit price (reserve_price applies per unit).
+// Winners get their requested quantity at their own unit price until the lot
+// runs out, the marginal bid being partially filled.
+impl BaseAuction for DiscriminatoryPriceAuction {
+    fn supports_bundle(&self, _auction_data: &AuctionData) -> bool {
+        false
+    }
+
+    fn accepts_bid(&self, auction_data: &AuctionData, amount: i128, quantity: i128) -> bool {
+        amount >= auction_data.reserve_price.saturating_mul(quantity)
+    }
+
+    fn resolve(&self, env: &Env, auction_id: u64) -> bool {
+        let auction_data = load_data::<DataKey, AuctionData>(env, &DataKey::AuctionData(auction_id));
+
+        if auction_data.start_time + auction_data.duration >= env.ledger().timestamp() {
+            return false;
+        }
+
+        // Each fill is charged at its own bid unit price.
+        self.finalize_fills(env, auction_id, &allocate_units(env, &auction_data))
+    }
+
+    fn calculate_price(&self, env: &Env, auction_id: u64) -> i128 {
+        load_data::<DataKey, AuctionData>(env, &DataKey::AuctionData(auction_id)).reserve_price
+    }
+}
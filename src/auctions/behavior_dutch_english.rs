@@ -0,0 +1,100 @@
+/*
+    Date: 2023
+    Author: Fred Kyung-jin Rezeau <fred@litemint.com>
+    Copyright (c) 2023 Litemint LLC
+
+    MIT License
+*/
+
+use soroban_sdk::{symbol_short, Env};
+use super::{
+    behavior::{best_bid, ledger_times, BaseAuction, AUCTION},
+    behavior_descending_price::{is_valid_schedule, scheduled_price},
+    bid_increment::minimum_bid,
+};
+use crate::{storage::*, types::{AuctionBehavior, AuctionData, DataKey, DutchEnglishData}};
+
+pub struct DutchEnglishAuction;
+
+// DutchEnglishAuction (aka Hybrid Auction).
+// The price descends following the price schedule until the best bid meets it,
+// the auction then switches to an ascending (English) auction ending after the
+// bidding window, where new bids must outbid the best bid (by the bid increment).
+impl BaseAuction for DutchEnglishAuction {
+    fn accepts_bid(&self, auction_data: &AuctionData, amount: i128, _quantity: i128) -> bool {
+        amount >= auction_data.reserve_price
+            && (!dutch_english_data(auction_data).english
+                || (auction_data.bids.iter().all(|bid| amount > bid.amount)
+                    && amount >= minimum_bid(auction_data)))
+    }
+
+    fn accepts_parameters(&self, auction_data: &AuctionData) -> bool {
+        is_valid_schedule(&dutch_english_data(auction_data).schedule)
+    }
+
+    fn resolve(&self, env: &Env, auction_id: u64) -> bool {
+        let mut auction_data = load_data::<DataKey, AuctionData>(env, &DataKey::AuctionData(auction_id));
+        let mut data = dutch_english_data(&auction_data);
+        let best_bid = best_bid(&auction_data);
+
+        if !data.english {
+            // Descending price is met, open the bidding window.
+            if let Some(bid) = &best_bid {
+                if bid.amount >= self.calculate_price(env, auction_id) {
+                    data.english = true;
+                    auction_data.duration =
+                        env.ledger().timestamp() - auction_data.start_time + data.bidding_window;
+                    auction_data.behavior = AuctionBehavior::DutchEnglish(data);
+                    save_data::<DataKey, AuctionData>(env, &DataKey::AuctionData(auction_id), &auction_data);
+
+                    let lifetime = ledger_times::convert_seconds_to_ledgers(
+                        auction_data.duration + ledger_times::EXPIRATION_BUFFER,
+                    );
+                    bump_data::<DataKey, AuctionData>(
+                        env,
+                        &DataKey::AuctionData(auction_id),
+                        lifetime,
+                        lifetime,
+                    );
+
+                    env.events()
+                        .publish((AUCTION, symbol_short!("english")), auction_id);
+                    return false;
+                }
+            }
+        }
+
+        // Auction has expired, the best bid wins once the price was met.
+        if auction_data.start_time + auction_data.duration < env.ledger().timestamp() {
+            return match &best_bid {
+                Some(bid) if data.english => self.finalize(env, auction_id, Some(bid)),
+                _ => self.finalize(env, auction_id, None),
+            };
+        }
+        false
+    }
+
+    fn calculate_price(&self, env: &Env, auction_id: u64) -> i128 {
+        let auction_data = load_data::<DataKey, AuctionData>(env, &DataKey::AuctionData(auction_id));
+        let data = dutch_english_data(&auction_data);
+        if data.english {
+            // Current best bid to outbid.
+            auction_data
+                .bids
+                .iter()
+                .map(|bid| bid.amount)
+                .max()
+                .unwrap_or(auction_data.reserve_price)
+        } else {
+            let elapsed = env.ledger().timestamp() - auction_data.start_time;
+            scheduled_price(&data.schedule, elapsed).max(auction_data.reserve_price)
+        }
+    }
+}
+
+fn dutch_english_data(auction_data: &AuctionData) -> DutchEnglishData {
+    match &auction_data.behavior {
+        AuctionBehavior::DutchEnglish(data) => data.clone(),
+        _ => panic!("Invalid parameters."),
+    }
+}
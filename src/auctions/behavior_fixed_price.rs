@@ -0,0 +1,59 @@
+/*
+    Date: 2023
+    Author: Fred Kyung-jin Rezeau <fred@litemint.com>
+    Copyright (c) 2023 Litemint LLC
+
+    MIT License
+*/
+
+use soroban_sdk::{Address, Env};
+use super::{behavior::BaseAuction, behavior_gradual_dutch::manage_purchase};
+use crate::{storage::*, types::{AuctionBehavior, AuctionData, DataKey}};
+
+pub struct FixedPriceAuction;
+
+// FixedPriceAuction (aka Buy Now listing).
+// Units of the lot are sold immediately at ask_price per unit. Buyers must
+// purchase the whole lot (and its bundle) unless the listing allows partial purchases.
+// Unsold units are returned to the seller when the listing expires.
+impl BaseAuction for FixedPriceAuction {
+    fn manage_bid(&self, env: &Env, auction_id: u64, buyer: &Address, amount: i128, quantity: i128) {
+        let auction_data = load_data::<DataKey, AuctionData>(env, &DataKey::AuctionData(auction_id));
+        if !allows_partial(&auction_data) && quantity != auction_data.amount {
+            panic!("Partial purchases not allowed.");
+        }
+
+        manage_purchase(self, env, auction_id, buyer, amount, quantity, |_, auction_data, quantity| {
+            auction_data.ask_price * quantity
+        });
+    }
+
+    fn resolve(&self, env: &Env, auction_id: u64) -> bool {
+        let auction_data = load_data::<DataKey, AuctionData>(env, &DataKey::AuctionData(auction_id));
+
+        // Listing has expired, unsold units are returned to the seller.
+        if auction_data.start_time + auction_data.duration < env.ledger().timestamp() {
+            return self.finalize(env, auction_id, None);
+        }
+        false
+    }
+
+    fn supports_bundle(&self, auction_data: &AuctionData) -> bool {
+        !allows_partial(auction_data)
+    }
+
+    fn accepts_parameters(&self, auction_data: &AuctionData) -> bool {
+        auction_data.ask_price > 0
+    }
+
+    fn calculate_price(&self, env: &Env, auction_id: u64) -> i128 {
+        load_data::<DataKey, AuctionData>(env, &DataKey::AuctionData(auction_id)).ask_price
+    }
+}
+
+fn allows_partial(auction_data: &AuctionData) -> bool {
+    match auction_data.behavior {
+        AuctionBehavior::FixedPrice(allow_partial) => allow_partial,
+        _ => false,
+    }
+}
@@ -0,0 +1,168 @@
+/*
+    Date: 2023
+    Author: Fred Kyung-jin Rezeau <fred@litemint.com>
+    Copyright (c) 2023 Litemint LLC
+
+    MIT License
+*/
+
+use soroban_sdk::{vec, Address, Env};
+use super::{behavior::BaseAuction, fixed_point};
+use crate::{storage::*, types::{AuctionBehavior, AuctionData, DataKey, GradualDutchData}};
+
+pub struct GradualDutchAuction;
+
+// GradualDutchAuction (aka GDA).
+// Units of the lot are sold immediately to any bid covering their price, which
+// decays continuously from ask_price as ask_price * e^(-decay_constant * elapsed)
+// and is multiplied by scale_factor for each unit sold (never below reserve_price).
+// Unsold units are returned to the seller when the auction expires.
+impl BaseAuction for GradualDutchAuction {
+    fn supports_bundle(&self, _auction_data: &AuctionData) -> bool {
+        false
+    }
+
+    fn accepts_parameters(&self, auction_data: &AuctionData) -> bool {
+        let gda_data = gda_data(auction_data);
+        auction_data.ask_price > 0
+            && gda_data.sold == 0
+            && gda_data.decay_constant > 0
+            && gda_data.scale_factor > fixed_point::ONE
+    }
+
+    fn manage_bid(&self, env: &Env, auction_id: u64, buyer: &Address, amount: i128, quantity: i128) {
+        manage_purchase(self, env, auction_id, buyer, amount, quantity, purchase_price);
+    }
+
+    fn resolve(&self, env: &Env, auction_id: u64) -> bool {
+        let auction_data = load_data::<DataKey, AuctionData>(env, &DataKey::AuctionData(auction_id));
+
+        // Auction has expired, unsold units are returned to the seller.
+        if auction_data.start_time + auction_data.duration < env.ledger().timestamp() {
+            return self.finalize(env, auction_id, None);
+        }
+        false
+    }
+
+    fn calculate_price(&self, env: &Env, auction_id: u64) -> i128 {
+        let auction_data = load_data::<DataKey, AuctionData>(env, &DataKey::AuctionData(auction_id));
+        purchase_price(env, &auction_data, 1)
+    }
+}
+
+// Buys a quantity of units immediately if the bid amount covers their price.
+pub fn manage_purchase<A, F>(
+    auction: &A,
+    env: &Env,
+    auction_id: u64,
+    buyer: &Address,
+    amount: i128,
+    quantity: i128,
+    price_of: F,
+) where
+    A: BaseAuction,
+    F: Fn(&Env, &AuctionData, i128) -> i128,
+{
+    // First check that the auction is resolved.
+    let resolved = auction.resolve(env, auction_id);
+    if resolved {
+        return;
+    }
+
+    let mut auction_data = load_data::<DataKey, AuctionData>(env, &DataKey::AuctionData(auction_id));
+    if amount == 0 {
+        panic!("No bid to cancel.");
+    }
+
+    if quantity <= 0 || quantity > auction_data.amount {
+        panic!("Invalid bid amount.");
+    }
+
+    let price = price_of(env, &auction_data, quantity);
+    if amount < price {
+        panic!("Invalid bid amount.");
+    }
+
+    auction.settle_sale(env, auction_id, &auction_data, buyer, quantity, price);
+
+    // Bundled tokens go along with the whole lot.
+    if quantity == auction_data.amount {
+        auction.deliver_bundle(env, &auction_data, buyer);
+        auction_data.bundle = vec![env];
+    }
+
+    // Track the units sold and the remaining lot.
+    auction_data.behavior = match auction_data.behavior {
+        AuctionBehavior::GradualDutch(mut data) => {
+            data.sold += quantity;
+            AuctionBehavior::GradualDutch(data)
+        }
+        AuctionBehavior::VariableRateGradualDutch(mut data) => {
+            data.sold += quantity;
+            AuctionBehavior::VariableRateGradualDutch(data)
+        }
+        behavior => behavior,
+    };
+    auction_data.amount -= quantity;
+    save_data::<DataKey, AuctionData>(env, &DataKey::AuctionData(auction_id), &auction_data);
+
+    // Lot is sold out.
+    if auction_data.amount == 0 {
+        auction.finalize(env, auction_id, None);
+    }
+}
+
+fn gda_data(auction_data: &AuctionData) -> GradualDutchData {
+    match &auction_data.behavior {
+        AuctionBehavior::GradualDutch(gda_data) => gda_data.clone(),
+        _ => panic!("Invalid parameters."),
+    }
+}
+
+// Sum of the prices of quantity units, starting at first_price and growing by ratio
+// (at least one) per unit, each floored at floor_price (fixed-point values).
+pub fn geometric_price(first_price: i128, ratio: i128, quantity: i128, floor_price: i128) -> i128 {
+    // Prices are increasing, find how many leading units are floored by bisection.
+    let (mut floored, mut high) = (0, quantity);
+    while floored < high {
+        let mid = (floored + high) / 2;
+        if fixed_point::mul(first_price, fixed_point::pow(ratio, mid as u64)) >= floor_price {
+            high = mid;
+        } else {
+            floored = mid + 1;
+        }
+    }
+
+    // Closed form p * (r^n - 1) / (r - 1) for the remaining units.
+    let price = fixed_point::mul(first_price, fixed_point::pow(ratio, floored as u64));
+    let remaining = quantity - floored;
+    let above_floor = if ratio == fixed_point::ONE {
+        price * remaining
+    } else {
+        fixed_point::div(
+            fixed_point::mul(price, fixed_point::pow(ratio, remaining as u64) - fixed_point::ONE),
+            ratio - fixed_point::ONE,
+        )
+    };
+    floored * floor_price + above_floor
+}
+
+// Total price of the next quantity of units at the current time.
+pub fn purchase_price(env: &Env, auction_data: &AuctionData, quantity: i128) -> i128 {
+    let gda_data = gda_data(auction_data);
+    let elapsed = (env.ledger().timestamp() - auction_data.start_time) as i128;
+    let decay = fixed_point::exp(-gda_data.decay_constant * elapsed);
+    let unit_price = fixed_point::mul(
+        auction_data.ask_price * decay,
+        fixed_point::pow(gda_data.scale_factor, gda_data.sold as u64),
+    );
+    let price = geometric_price(
+        unit_price,
+        gda_data.scale_factor,
+        quantity,
+        auction_data.reserve_price * fixed_point::ONE,
+    );
+
+    // Round up to the smallest unit.
+    (price + fixed_point::ONE - 1) / fixed_point::ONE
+}
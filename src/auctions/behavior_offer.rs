@@ -0,0 +1,52 @@
+/*
+    Date: 2023
+    Author: Fred Kyung-jin Rezeau <fred@litemint.com>
+    Copyright (c) 2023 Litemint LLC
+
+    MIT License
+*/
+
+use soroban_sdk::{Address, Env};
+use super::behavior::BaseAuction;
+use crate::{storage::*, types::{AuctionData, DataKey}};
+
+pub struct OfferAuction;
+
+// OfferAuction (aka Make-Offer Listing).
+// Buyers escrow offers of any amount (at least reserve_price) and may cancel them
+// until the seller accepts one at its discretion, the other offers being refunded.
+// Offers are refunded and the lot returned to the seller when the listing expires.
+impl BaseAuction for OfferAuction {
+    fn anti_snipe(&self, _env: &Env, _auction_data: &AuctionData) -> (u64, bool) {
+        (0, false)
+    }
+
+    fn resolve(&self, env: &Env, auction_id: u64) -> bool {
+        let auction_data = load_data::<DataKey, AuctionData>(env, &DataKey::AuctionData(auction_id));
+
+        // Listing has expired without an accepted offer.
+        if auction_data.start_time + auction_data.duration < env.ledger().timestamp() {
+            return self.finalize(env, auction_id, None);
+        }
+        false
+    }
+
+    fn calculate_price(&self, env: &Env, auction_id: u64) -> i128 {
+        load_data::<DataKey, AuctionData>(env, &DataKey::AuctionData(auction_id)).reserve_price
+    }
+}
+
+impl OfferAuction {
+    pub fn accept_offer(&self, env: &Env, auction_id: u64, buyer: &Address) {
+        // First check that the listing is still open.
+        if self.resolve(env, auction_id) {
+            panic!("Listing has ended.");
+        }
+
+        let auction_data = load_data::<DataKey, AuctionData>(env, &DataKey::AuctionData(auction_id));
+        match auction_data.bids.iter().find(|bid| bid.buyer == *buyer && bid.amount > 0) {
+            Some(bid) => self.finalize(env, auction_id, Some(&bid)),
+            None => panic!("No offer to accept."),
+        };
+    }
+}
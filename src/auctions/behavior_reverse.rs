@@ -0,0 +1,126 @@
+/*
+    Date: 2023
+    Author: Fred Kyung-jin Rezeau <fred@litemint.com>
+    Copyright (c) 2023 Litemint LLC
+
+    MIT License
+*/
+
+use soroban_sdk::{symbol_short, token, Address, Env};
+use super::behavior::{delete_auction, BaseAuction, AUCTION};
+use crate::{storage::*, types::{AdminData, AuctionData, BidData, DataKey}};
+
+pub struct ReverseAuction;
+
+// ReverseAuction (aka Procurement Auction).
+// Roles are swapped: the seller escrows a budget (reserve_price) in the market
+// token and asks for an amount of token. Suppliers escrow the requested amount
+// of token along with decreasing asks, and the lowest ask wins at expiry
+// (or immediately when at or below the ask price, if set).
+impl BaseAuction for ReverseAuction {
+    fn supports_bundle(&self, _auction_data: &AuctionData) -> bool {
+        false
+    }
+
+    fn accepts_bid(&self, auction_data: &AuctionData, amount: i128, quantity: i128) -> bool {
+        // Asks must fit the budget, deliver the whole lot and beat the lowest ask.
+        amount <= auction_data.reserve_price
+            && quantity == auction_data.amount
+            && auction_data.bids.iter().all(|bid| amount < bid.amount)
+    }
+
+    fn accepts_parameters(&self, auction_data: &AuctionData) -> bool {
+        auction_data.reserve_price > 0
+    }
+
+    fn escrow_lot(&self, env: &Env, auction_data: &AuctionData) {
+        let market = token::Client::new(env, &auction_data.market);
+        market.transfer(&auction_data.seller, &env.current_contract_address(), &auction_data.reserve_price);
+    }
+
+    fn release_lot(&self, env: &Env, auction_data: &AuctionData) {
+        let market = token::Client::new(env, &auction_data.market);
+        market.transfer(&env.current_contract_address(), &auction_data.seller, &auction_data.reserve_price);
+    }
+
+    fn escrow_bid(
+        &self,
+        env: &Env,
+        auction_data: &AuctionData,
+        buyer: &Address,
+        _amount: i128,
+        quantity: i128,
+    ) {
+        let token = token::Client::new(env, &auction_data.token);
+        token.transfer(buyer, &env.current_contract_address(), &quantity);
+    }
+
+    fn refund_bid(&self, env: &Env, _auction_id: u64, auction_data: &AuctionData, bid: &BidData) {
+        let token = token::Client::new(env, &auction_data.token);
+        token.transfer(&env.current_contract_address(), &bid.buyer, &bid.quantity);
+    }
+
+    fn finalize(&self, env: &Env, auction_id: u64, winner: Option<&BidData>) -> bool {
+        let auction_data = load_data::<DataKey, AuctionData>(env, &DataKey::AuctionData(auction_id));
+        let market = token::Client::new(env, &auction_data.market);
+        let mut budget = auction_data.reserve_price;
+
+        if let Some(bid) = winner {
+            // We have a winner, deliver the lot to the seller and pay the supplier.
+            let admin_data = load_data::<DataKey, AdminData>(env, &DataKey::AdminData);
+            let token = token::Client::new(env, &auction_data.token);
+            let admin_share = bid.amount * admin_data.commission_rate / 100;
+            let supplier_share = bid.amount - admin_share;
+
+            token.transfer(&env.current_contract_address(), &auction_data.seller, &bid.quantity);
+            market.transfer(&env.current_contract_address(), &admin_data.admin, &admin_share);
+            market.transfer(&env.current_contract_address(), &bid.buyer, &supplier_share);
+            budget -= bid.amount;
+        }
+
+        // Refund the remaining budget to the seller.
+        if budget > 0 {
+            market.transfer(&env.current_contract_address(), &auction_data.seller, &budget);
+        }
+
+        // Cancel all other asks.
+        for bid in auction_data.bids.iter() {
+            if winner.map(|w| &w.buyer) != Some(&bid.buyer) {
+                self.refund_bid(env, auction_id, &auction_data, &bid);
+            }
+        }
+
+        // Delete the auction.
+        delete_auction(env, auction_id, &auction_data);
+        if winner.is_some() {
+            env.events()
+                .publish((AUCTION, symbol_short!("won")), auction_id);
+        } else {
+            env.events()
+                .publish((AUCTION, symbol_short!("ended")), auction_id);
+        }
+        true
+    }
+
+    fn resolve(&self, env: &Env, auction_id: u64) -> bool {
+        let auction_data = load_data::<DataKey, AuctionData>(env, &DataKey::AuctionData(auction_id));
+
+        // Retrieve the lowest ask.
+        if let Some(bid) = auction_data.bids.iter().min_by_key(|bid| (bid.amount, bid.sequence)) {
+            // Either the auction time has expired or the ask price is met.
+            if auction_data.start_time + auction_data.duration < env.ledger().timestamp()
+                || (auction_data.ask_price > 0 && bid.amount <= auction_data.ask_price)
+            {
+                return self.finalize(env, auction_id, Some(&bid));
+            }
+        } else if auction_data.start_time + auction_data.duration < env.ledger().timestamp() {
+            // Auction has expired.
+            return self.finalize(env, auction_id, None);
+        }
+        false
+    }
+
+    fn calculate_price(&self, env: &Env, auction_id: u64) -> i128 {
+        load_data::<DataKey, AuctionData>(env, &DataKey::AuctionData(auction_id)).reserve_price
+    }
+}
@@ -0,0 +1,233 @@
+/*
+    Date: 2023
+    Author: Fred Kyung-jin Rezeau <fred@litemint.com>
+    Copyright (c) 2023 Litemint LLC
+
+    MIT License
+*/
+
+use soroban_sdk::{symbol_short, token, vec, xdr::ToXdr, Address, Bytes, BytesN, Env};
+use super::behavior::{best_bid, cancel_auction, ledger_times, next_sequence, BaseAuction, BID};
+use crate::{
+    storage::*,
+    types::{AdminData, AuctionBehavior, AuctionData, BidData, CommitmentData, DataKey, SealedBidData},
+};
+
+pub struct SealedBidAuction;
+
+// SealedBidAuction (aka First-Price Sealed-Bid Auction).
+// Bidders commit sha256(auction_id || buyer || amount || salt) along with a deposit
+// covering the bid during the auction duration, then reveal during the reveal duration.
+// Rules: mismatched reveals are rejected, unrevealed commitments are excluded
+// from the auction and their deposits forfeited to the admin at settlement
+// (refunded if the auction is cancelled), so committing unrevealed shill bids is costly.
+impl BaseAuction for SealedBidAuction {
+    fn manage_bid(
+        &self,
+        _env: &Env,
+        _auction_id: u64,
+        _buyer: &Address,
+        _amount: i128,
+        _quantity: i128,
+    ) {
+        panic!("Sealed bids must be committed.");
+    }
+
+    fn settlement_time(&self, auction_data: &AuctionData) -> u64 {
+        reveal_duration(auction_data)
+    }
+
+    fn cancel(&self, env: &Env, auction_id: u64) {
+        // Refund all commitments.
+        let auction_data = load_data::<DataKey, AuctionData>(env, &DataKey::AuctionData(auction_id));
+        settle_commitments(env, auction_id, &auction_data, false);
+        cancel_auction(self, env, auction_id);
+    }
+
+    fn resolve(&self, env: &Env, auction_id: u64) -> bool {
+        let auction_data = load_data::<DataKey, AuctionData>(env, &DataKey::AuctionData(auction_id));
+
+        // Reveal phase is still open.
+        if auction_data.start_time + auction_data.duration + reveal_duration(&auction_data)
+            >= env.ledger().timestamp()
+        {
+            return false;
+        }
+
+        // Forfeit unrevealed commitments.
+        settle_commitments(env, auction_id, &auction_data, true);
+
+        // Retrieve the highest revealed bid.
+        match best_bid(&auction_data) {
+            Some(bid) if bid.amount >= auction_data.reserve_price => {
+                self.finalize(env, auction_id, Some(&bid))
+            }
+            _ => self.finalize(env, auction_id, None),
+        }
+    }
+
+    fn calculate_price(&self, env: &Env, auction_id: u64) -> i128 {
+        load_data::<DataKey, AuctionData>(env, &DataKey::AuctionData(auction_id)).reserve_price
+    }
+}
+
+impl SealedBidAuction {
+    pub fn commit_bid(
+        &self,
+        env: &Env,
+        auction_id: u64,
+        buyer: &Address,
+        commitment: &BytesN<32>,
+        deposit: i128,
+    ) {
+        let auction_data = load_data::<DataKey, AuctionData>(env, &DataKey::AuctionData(auction_id));
+        if auction_data.start_time + auction_data.duration < env.ledger().timestamp() {
+            panic!("Bidding phase is over.");
+        }
+
+        if deposit <= 0 || deposit < auction_data.reserve_price {
+            panic!("Invalid bid amount.");
+        }
+
+        let key = DataKey::SealedBidData(auction_id);
+        let mut sealed_data = load_data_or_else::<DataKey, SealedBidData, _, _>(env, &key, |opt| {
+            opt.unwrap_or_else(|| SealedBidData {
+                commitments: vec![env],
+            })
+        });
+        if sealed_data.commitments.iter().any(|c| c.buyer == *buyer) {
+            panic!("Not allowed to place new bid.");
+        }
+
+        // Escrow the deposit, which hides the bid amount up to its value.
+        let market = token::Client::new(env, &auction_data.market);
+        market.transfer(buyer, &env.current_contract_address(), &deposit);
+
+        sealed_data.commitments.push_back(CommitmentData {
+            buyer: buyer.clone(),
+            commitment: commitment.clone(),
+            deposit,
+            revealed: false,
+        });
+        save_data::<DataKey, SealedBidData>(env, &key, &sealed_data);
+
+        // Keep commitments alive as long as the auction.
+        let lifetime = ledger_times::convert_seconds_to_ledgers(
+            auction_data.start_time + auction_data.duration + reveal_duration(&auction_data)
+                - env.ledger().timestamp()
+                + ledger_times::EXPIRATION_BUFFER,
+        );
+        bump_data::<DataKey, SealedBidData>(env, &key, lifetime, lifetime);
+
+        env.events().publish((BID, symbol_short!("committed")), auction_id);
+    }
+
+    pub fn reveal_bid(
+        &self,
+        env: &Env,
+        auction_id: u64,
+        buyer: &Address,
+        amount: i128,
+        salt: &BytesN<32>,
+    ) {
+        let mut auction_data = load_data::<DataKey, AuctionData>(env, &DataKey::AuctionData(auction_id));
+        let end_time = auction_data.start_time + auction_data.duration;
+        let now = env.ledger().timestamp();
+        if now <= end_time || now > end_time + reveal_duration(&auction_data) {
+            panic!("Not in reveal phase.");
+        }
+
+        let key = DataKey::SealedBidData(auction_id);
+        let mut sealed_data = load_data::<DataKey, SealedBidData>(env, &key);
+        let index = sealed_data
+            .commitments
+            .iter()
+            .position(|c| c.buyer == *buyer && !c.revealed)
+            .unwrap_or_else(|| panic!("No bid to reveal.")) as u32;
+        let mut commitment = sealed_data.commitments.get_unchecked(index);
+
+        if commitment.commitment != compute_commitment(env, auction_id, buyer, amount, salt) {
+            panic!("Invalid bid commitment.");
+        }
+
+        if amount <= 0 || amount < auction_data.reserve_price || amount > commitment.deposit {
+            panic!("Invalid bid amount.");
+        }
+
+        // Refund the part of the deposit exceeding the bid.
+        if commitment.deposit > amount {
+            let market = token::Client::new(env, &auction_data.market);
+            market.transfer(
+                &env.current_contract_address(),
+                buyer,
+                &(commitment.deposit - amount),
+            );
+        }
+
+        commitment.revealed = true;
+        sealed_data.commitments.set(index, commitment);
+        save_data::<DataKey, SealedBidData>(env, &key, &sealed_data);
+
+        let sequence = next_sequence(&mut auction_data);
+        auction_data.bids.push_back(BidData {
+            buyer: buyer.clone(),
+            amount,
+            quantity: auction_data.amount,
+            sniper: false,
+            timestamp: now,
+            placed_at: now,
+            sequence,
+        });
+        save_data::<DataKey, AuctionData>(env, &DataKey::AuctionData(auction_id), &auction_data);
+        env.events().publish((BID, symbol_short!("revealed")), auction_id);
+    }
+}
+
+// Commitment hash for a sealed bid: sha256(auction_id as big-endian u64 || buyer as XDR
+// || amount as big-endian i128 || salt), binding the commitment to the auction and bidder.
+pub fn compute_commitment(
+    env: &Env,
+    auction_id: u64,
+    buyer: &Address,
+    amount: i128,
+    salt: &BytesN<32>,
+) -> BytesN<32> {
+    let mut data = Bytes::from_array(env, &auction_id.to_be_bytes());
+    data.append(&buyer.clone().to_xdr(env));
+    data.extend_from_array(&amount.to_be_bytes());
+    data.extend_from_array(&salt.to_array());
+    env.crypto().sha256(&data)
+}
+
+// Settles unrevealed commitments, forfeiting their deposits to the admin
+// (or refunding them if not forfeit), and removes the commitment data.
+pub fn settle_commitments(env: &Env, auction_id: u64, auction_data: &AuctionData, forfeit: bool) {
+    let key = DataKey::SealedBidData(auction_id);
+    if let Some(sealed_data) = load_data_or_else::<DataKey, SealedBidData, _, _>(env, &key, |opt| opt) {
+        let market = token::Client::new(env, &auction_data.market);
+        let mut forfeited = 0;
+        for c in sealed_data.commitments.iter() {
+            if c.revealed {
+                continue;
+            }
+            if forfeit {
+                forfeited += c.deposit;
+            } else {
+                market.transfer(&env.current_contract_address(), &c.buyer, &c.deposit);
+            }
+        }
+        if forfeited > 0 {
+            let admin = load_data::<DataKey, AdminData>(env, &DataKey::AdminData).admin;
+            market.transfer(&env.current_contract_address(), &admin, &forfeited);
+        }
+        delete_data::<DataKey, SealedBidData>(env, &key);
+    }
+}
+
+pub fn reveal_duration(auction_data: &AuctionData) -> u64 {
+    match auction_data.behavior {
+        AuctionBehavior::SealedBid(reveal_duration)
+        | AuctionBehavior::Vickrey(reveal_duration) => reveal_duration,
+        _ => 0,
+    }
+}
@@ -0,0 +1,99 @@
+/*
+    Date: 2023
+    Author: Fred Kyung-jin Rezeau <fred@litemint.com>
+    Copyright (c) 2023 Litemint LLC
+
+    MIT License
+*/
+
+use soroban_sdk::{vec, Env, Vec};
+use super::behavior::BaseAuction;
+use crate::{storage::*, types::{AuctionData, BidData, DataKey, FillData}};
+
+pub struct UniformPriceAuction;
+
+// UniformPriceAuction (aka Multi-Unit Uniform Price Auction).
+// Bids name a quantity and a unit price (reserve_price applies per unit).
+// Units go to the highest unit bids, the marginal bid being partially filled,
+// and every winner pays the lowest accepted unit price.
+impl BaseAuction for UniformPriceAuction {
+    fn supports_bundle(&self, _auction_data: &AuctionData) -> bool {
+        false
+    }
+
+    fn accepts_bid(&self, auction_data: &AuctionData, amount: i128, quantity: i128) -> bool {
+        amount >= auction_data.reserve_price.saturating_mul(quantity)
+    }
+
+    fn resolve(&self, env: &Env, auction_id: u64) -> bool {
+        let auction_data = load_data::<DataKey, AuctionData>(env, &DataKey::AuctionData(auction_id));
+
+        if auction_data.start_time + auction_data.duration >= env.ledger().timestamp() {
+            return false;
+        }
+
+        // Clearing price is the lowest accepted unit price.
+        let allocation = allocate_units(env, &auction_data);
+        let clearing_price = allocation
+            .iter()
+            .map(|fill| fill.price / fill.quantity)
+            .min()
+            .unwrap_or(0);
+
+        let mut fills: Vec<FillData> = vec![env];
+        for fill in allocation.iter() {
+            fills.push_back(FillData {
+                buyer: fill.buyer,
+                quantity: fill.quantity,
+                price: fill.quantity * clearing_price,
+            });
+        }
+        self.finalize_fills(env, auction_id, &fills)
+    }
+
+    fn calculate_price(&self, env: &Env, auction_id: u64) -> i128 {
+        load_data::<DataKey, AuctionData>(env, &DataKey::AuctionData(auction_id)).reserve_price
+    }
+}
+
+pub fn unit_price(bid: &BidData) -> i128 {
+    bid.amount / bid.quantity
+}
+
+// Allocates the lot to the highest unit price bids (earliest bid first on ties).
+// Returns the fills priced at each bid unit price, the last one possibly partial.
+pub fn allocate_units(env: &Env, auction_data: &AuctionData) -> Vec<FillData> {
+    let mut fills: Vec<FillData> = vec![env];
+    let mut allocated: Vec<u32> = vec![env];
+    let mut remaining = auction_data.amount;
+    while remaining > 0 {
+        let mut best: Option<(u32, BidData)> = None;
+        for (index, bid) in auction_data.bids.iter().enumerate() {
+            let index = index as u32;
+            if bid.quantity == 0 || allocated.contains(index) {
+                continue;
+            }
+            if let Some((_, b)) = &best {
+                if unit_price(&bid) <= unit_price(b) {
+                    continue;
+                }
+            }
+            best = Some((index, bid));
+        }
+
+        match best {
+            Some((index, bid)) => {
+                let quantity = bid.quantity.min(remaining);
+                fills.push_back(FillData {
+                    buyer: bid.buyer.clone(),
+                    quantity,
+                    price: quantity * unit_price(&bid),
+                });
+                allocated.push_back(index);
+                remaining -= quantity;
+            }
+            None => break,
+        }
+    }
+    fills
+}
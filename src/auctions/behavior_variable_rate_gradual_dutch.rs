@@ -0,0 +1,94 @@
+/*
+    Date: 2023
+    Author: Fred Kyung-jin Rezeau <fred@litemint.com>
+    Copyright (c) 2023 Litemint LLC
+
+    MIT License
+*/
+
+use soroban_sdk::{Address, Env};
+use super::{
+    behavior::BaseAuction,
+    behavior_gradual_dutch::{geometric_price, manage_purchase},
+    fixed_point,
+};
+use crate::{
+    storage::*,
+    types::{AuctionBehavior, AuctionData, DataKey, VariableRateGradualDutchData},
+};
+
+pub struct VariableRateGradualDutchAuction;
+
+// VariableRateGradualDutchAuction (aka VRGDA).
+// Units of the lot are sold immediately at ask_price (the target price) adjusted
+// by e^(decay_constant * (sold / units_per_second - elapsed)): the price rises
+// when sales run ahead of the issuance schedule and decays when they fall behind
+// (never below reserve_price). Unsold units are returned to the seller on expiry.
+impl BaseAuction for VariableRateGradualDutchAuction {
+    fn supports_bundle(&self, _auction_data: &AuctionData) -> bool {
+        false
+    }
+
+    fn accepts_parameters(&self, auction_data: &AuctionData) -> bool {
+        let vrgda_data = vrgda_data(auction_data);
+        auction_data.ask_price > 0
+            && vrgda_data.sold == 0
+            && vrgda_data.units_per_second > 0
+            && vrgda_data.decay_constant > 0
+    }
+
+    fn manage_bid(&self, env: &Env, auction_id: u64, buyer: &Address, amount: i128, quantity: i128) {
+        manage_purchase(self, env, auction_id, buyer, amount, quantity, purchase_price);
+    }
+
+    fn resolve(&self, env: &Env, auction_id: u64) -> bool {
+        let auction_data = load_data::<DataKey, AuctionData>(env, &DataKey::AuctionData(auction_id));
+
+        // Auction has expired, unsold units are returned to the seller.
+        if auction_data.start_time + auction_data.duration < env.ledger().timestamp() {
+            return self.finalize(env, auction_id, None);
+        }
+        false
+    }
+
+    fn calculate_price(&self, env: &Env, auction_id: u64) -> i128 {
+        let auction_data = load_data::<DataKey, AuctionData>(env, &DataKey::AuctionData(auction_id));
+        purchase_price(env, &auction_data, 1)
+    }
+}
+
+fn vrgda_data(auction_data: &AuctionData) -> VariableRateGradualDutchData {
+    match &auction_data.behavior {
+        AuctionBehavior::VariableRateGradualDutch(vrgda_data) => vrgda_data.clone(),
+        _ => panic!("Invalid parameters."),
+    }
+}
+
+// Total price of the next quantity of units at the current time.
+pub fn purchase_price(env: &Env, auction_data: &AuctionData, quantity: i128) -> i128 {
+    let vrgda_data = vrgda_data(auction_data);
+    let elapsed = (env.ledger().timestamp() - auction_data.start_time) as i128 * fixed_point::ONE;
+
+    // Time at which the next unit is scheduled to sell, minus the elapsed time.
+    let schedule_offset =
+        fixed_point::div(vrgda_data.sold * fixed_point::ONE, vrgda_data.units_per_second) - elapsed;
+    let unit_price = fixed_point::mul(
+        auction_data.ask_price * fixed_point::ONE,
+        fixed_point::exp(fixed_point::mul(vrgda_data.decay_constant, schedule_offset)),
+    );
+
+    // Each further unit is scheduled 1 / units_per_second later.
+    let ratio = fixed_point::exp(fixed_point::div(
+        vrgda_data.decay_constant,
+        vrgda_data.units_per_second,
+    ));
+    let price = geometric_price(
+        unit_price,
+        ratio,
+        quantity,
+        auction_data.reserve_price * fixed_point::ONE,
+    );
+
+    // Round up to the smallest unit.
+    (price + fixed_point::ONE - 1) / fixed_point::ONE
+}
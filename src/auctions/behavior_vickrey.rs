@@ -0,0 +1,78 @@
+/*
+    Date: 2023
+    Author: Fred Kyung-jin Rezeau <fred@litemint.com>
+    Copyright (c) 2023 Litemint LLC
+
+    MIT License
+*/
+
+use soroban_sdk::{Address, Env};
+use super::behavior::{best_bid, cancel_auction, BaseAuction};
+use super::behavior_sealed_bid::{reveal_duration, settle_commitments};
+use crate::{storage::*, types::{AuctionData, DataKey}};
+
+pub struct VickreyAuction;
+
+// VickreyAuction (aka Second-Price Sealed-Bid Auction).
+// Bids are committed and revealed as for SealedBidAuction, the highest bidder
+// wins and pays the second-highest bid (or the reserve price if only one bid).
+// Commitments are bound to the bidder, so copying a rival's commitment to set
+// the second price cannot be revealed and forfeits the copied deposit.
+impl BaseAuction for VickreyAuction {
+    fn manage_bid(
+        &self,
+        _env: &Env,
+        _auction_id: u64,
+        _buyer: &Address,
+        _amount: i128,
+        _quantity: i128,
+    ) {
+        panic!("Sealed bids must be committed.");
+    }
+
+    fn settlement_time(&self, auction_data: &AuctionData) -> u64 {
+        reveal_duration(auction_data)
+    }
+
+    fn cancel(&self, env: &Env, auction_id: u64) {
+        // Refund all commitments.
+        let auction_data = load_data::<DataKey, AuctionData>(env, &DataKey::AuctionData(auction_id));
+        settle_commitments(env, auction_id, &auction_data, false);
+        cancel_auction(self, env, auction_id);
+    }
+
+    fn resolve(&self, env: &Env, auction_id: u64) -> bool {
+        let auction_data = load_data::<DataKey, AuctionData>(env, &DataKey::AuctionData(auction_id));
+
+        // Reveal phase is still open.
+        if auction_data.start_time + auction_data.duration + reveal_duration(&auction_data)
+            >= env.ledger().timestamp()
+        {
+            return false;
+        }
+
+        // Forfeit unrevealed commitments.
+        settle_commitments(env, auction_id, &auction_data, true);
+
+        // Retrieve the highest revealed bid.
+        match best_bid(&auction_data) {
+            Some(bid) if bid.amount >= auction_data.reserve_price => {
+                // Clearing price is the second-highest bid, or the reserve.
+                let price = auction_data
+                    .bids
+                    .iter()
+                    .filter(|b| b.buyer != bid.buyer)
+                    .map(|b| b.amount)
+                    .max()
+                    .unwrap_or(auction_data.reserve_price)
+                    .max(auction_data.reserve_price);
+                self.finalize_at_price(env, auction_id, &bid, price)
+            }
+            _ => self.finalize(env, auction_id, None),
+        }
+    }
+
+    fn calculate_price(&self, env: &Env, auction_id: u64) -> i128 {
+        load_data::<DataKey, AuctionData>(env, &DataKey::AuctionData(auction_id)).reserve_price
+    }
+}
@@ -0,0 +1,56 @@
+/*
+    Date: 2023
+    Author: Fred Kyung-jin Rezeau <fred@litemint.com>
+    Copyright (c) 2023 Litemint LLC
+
+    MIT License
+*/
+
+//! Minimum bid increment rules, requiring new bids to exceed
+//! the current best bid by an absolute, percentage or tiered increment.
+
+use crate::types::{AuctionData, BidIncrement};
+
+// Minimum amount for a new bid on the auction.
+pub fn minimum_bid(auction_data: &AuctionData) -> i128 {
+    match auction_data.bids.iter().map(|bid| bid.amount).max() {
+        Some(best) if auction_data.bid_increment != BidIncrement::None => {
+            best + increment_over(&auction_data.bid_increment, best)
+        }
+        _ => auction_data.reserve_price,
+    }
+    .max(auction_data.reserve_price)
+}
+
+// Increment required over the given best bid.
+pub fn increment_over(bid_increment: &BidIncrement, best: i128) -> i128 {
+    match bid_increment {
+        BidIncrement::None => 0,
+        BidIncrement::Absolute(increment) => *increment,
+        // Round up to the smallest unit.
+        BidIncrement::Percent(percent) => (best * *percent as i128 + 99) / 100,
+        // Increment of the highest price band reached by the best bid.
+        BidIncrement::Tiered(tiers) => tiers
+            .iter()
+            .filter(|tier| tier.from_price <= best)
+            .last()
+            .map_or(0, |tier| tier.increment),
+    }
+}
+
+pub fn is_valid_increment(bid_increment: &BidIncrement) -> bool {
+    match bid_increment {
+        BidIncrement::None => true,
+        BidIncrement::Absolute(increment) => *increment > 0,
+        BidIncrement::Percent(percent) => *percent > 0,
+        BidIncrement::Tiered(tiers) => {
+            // First band must cover every price.
+            tiers.first().is_some_and(|tier| tier.from_price == 0)
+                && tiers.iter().all(|tier| tier.increment > 0)
+                && tiers
+                    .iter()
+                    .zip(tiers.iter().skip(1))
+                    .all(|(a, b)| a.from_price < b.from_price)
+        }
+    }
+}
@@ -0,0 +1,74 @@
+/*
+    Date: 2023
+    Author: Fred Kyung-jin Rezeau <fred@litemint.com>
+    Copyright (c) 2023 Litemint LLC
+
+    MIT License
+*/
+
+//! Signed fixed-point arithmetic with 18 decimals, used by
+//! continuous pricing functions (e.g. exponential decay).
+
+pub const ONE: i128 = 1_000_000_000_000_000_000;
+const LN_2: i128 = 693_147_180_559_945_309;
+
+pub fn mul(a: i128, b: i128) -> i128 {
+    match a.checked_mul(b) {
+        Some(product) => product / ONE,
+        // Trade precision for range on large operands.
+        None => (a / ONE).checked_mul(b).expect("Fixed-point overflow."),
+    }
+}
+
+pub fn div(a: i128, b: i128) -> i128 {
+    match a.checked_mul(ONE) {
+        Some(product) => product / b,
+        // Trade precision for range on large operands.
+        None => (a / b).checked_mul(ONE).expect("Fixed-point overflow."),
+    }
+}
+
+// Raises a fixed-point base to an integer power (square-and-multiply).
+pub fn pow(base: i128, exponent: u64) -> i128 {
+    let mut result = ONE;
+    let mut base = base;
+    let mut exponent = exponent;
+    while exponent > 0 {
+        if exponent & 1 == 1 {
+            result = mul(result, base);
+        }
+        exponent >>= 1;
+        if exponent > 0 {
+            base = mul(base, base);
+        }
+    }
+    result
+}
+
+// Computes e^x, reducing x = k * ln(2) + r and summing the Taylor series of e^r.
+pub fn exp(x: i128) -> i128 {
+    // e^x is below 1e-18 under -42.
+    if x < -42 * ONE {
+        return 0;
+    }
+    if x > 46 * ONE {
+        panic!("Fixed-point overflow.");
+    }
+
+    let k = x / LN_2;
+    let r = x - k * LN_2;
+    let mut term = ONE;
+    let mut sum = ONE;
+    let mut i = 1;
+    while term != 0 {
+        term = term * r / ONE / i;
+        sum += term;
+        i += 1;
+    }
+
+    if k >= 0 {
+        sum.checked_mul(1i128 << k).expect("Fixed-point overflow.")
+    } else {
+        sum >> -k
+    }
+}
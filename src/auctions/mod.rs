@@ -13,12 +13,52 @@
 //! Implemented features:
 //! 
 //! - Descending price auctions (see: behavior_descending_price.rs) supporting linear 
-//!   or compound discount, and customizable frequency/rate.
-//! - Ascending price auctions (see: behavior_ascending_price.rs) with "buy now" option.
+//!   or compound discount, and customizable frequency/rate, or piecewise custom
+//!   price schedules (step or linear).
+//! - Ascending price auctions (see: behavior_ascending_price.rs) with "buy now" option
+//!   and proxy (maximum) bidding.
+//! - Sealed-bid first-price auctions (see: behavior_sealed_bid.rs) with commit/reveal,
+//!   unrevealed deposits being forfeited.
+//! - Vickrey auctions (see: behavior_vickrey.rs) settling at the second-highest sealed bid.
+//! - Candle auctions (see: behavior_candle.rs) closing at a random time within the candle window.
+//! - Multi-unit uniform price auctions (see: behavior_uniform_price.rs) with partial fills.
+//! - Multi-unit discriminatory (pay-as-bid) auctions (see: behavior_discriminatory_price.rs).
+//! - Reverse (procurement) auctions (see: behavior_reverse.rs) won by the lowest ask.
+//! - Clock auctions (see: behavior_clock.rs) with rising rounds and bidder dropout.
+//! - Gradual Dutch auctions (see: behavior_gradual_dutch.rs) for paced mints with exponential decay.
+//! - Variable rate gradual Dutch auctions (see: behavior_variable_rate_gradual_dutch.rs)
+//!   pacing mints on a target issuance schedule.
+//! - Fixed-price "buy now" listings (see: behavior_fixed_price.rs) with optional partial purchases.
+//! - Make-offer listings (see: behavior_offer.rs) settled by seller acceptance.
+//! - Hybrid Dutch-then-English auctions (see: behavior_dutch_english.rs) opening a bidding
+//!   window once the descending price is met.
+//! - Bundle lots of several (token, amount) pairs sold together.
+//! - Concurrent auctions per seller, identified by auction IDs.
 //! - Reserve price.
-//! - Anti-snipe mechanism.
-//! - Concurrent and cancellable bids.
+//! - Minimum bid increments (see: bid_increment.rs), absolute, percentage or tiered.
+//! - Anti-snipe mechanism with per-auction soft-close policies.
+//! - Seller amendments of reserve, ask, discount and price schedule.
+//! - Scheduled auctions opening at a future start time.
+//! - Seller cancellation, with bids only if allowed by the admin.
+//! - Concurrent, cancellable and raisable bids.
+//! - Bidder allowlists and token-gated participation.
+//! - Bid cancellation policies (free retraction window, cancellation fee, irrevocability cutoff).
+//! - Deterministic tie-breaking (earliest bid wins, or ties rejected) using bid sequences.
 
 pub mod behavior;
 pub mod behavior_descending_price;
-pub mod behavior_ascending_price;
\ No newline at end of file
+pub mod behavior_discriminatory_price;
+pub mod behavior_dutch_english;
+pub mod behavior_fixed_price;
+pub mod behavior_gradual_dutch;
+pub mod behavior_offer;
+pub mod fixed_point;
+pub mod behavior_ascending_price;
+pub mod behavior_candle;
+pub mod behavior_clock;
+pub mod behavior_reverse;
+pub mod behavior_sealed_bid;
+pub mod behavior_uniform_price;
+pub mod behavior_variable_rate_gradual_dutch;
+pub mod behavior_vickrey;
+pub mod bid_increment;
\ No newline at end of file
@@ -13,10 +13,19 @@ mod storage; // Contract data storage.
 mod auctions; // Auction behaviors and mechanisms.
 mod types; // Contract types.
 
-use crate::auctions::{behavior::BaseAuction, behavior::Dispatcher};
+use crate::auctions::{
+    behavior::{BaseAuction, AUCTION}, behavior_ascending_price::{has_proxy_bid, AscendingPriceAuction},
+    behavior_descending_price::{is_valid_discount, is_valid_schedule},
+    bid_increment::is_valid_increment, behavior::Dispatcher, behavior_offer::OfferAuction,
+    behavior_sealed_bid::SealedBidAuction,
+};
 use crate::storage::*;
-use soroban_sdk::{contract, contractimpl, contractmeta, vec, Address, Env, Vec};
-use types::{AdminData, AuctionData, BidData, DataKey};
+use soroban_sdk::{contract, contractimpl, contractmeta, symbol_short, token, vec, Address, BytesN, Env, Vec};
+use types::{
+    AdminData, Amendment, AuctionBehavior, AuctionData, BidData, BidIncrement, CancellationData,
+    CancellationPolicy, DataKey, DiscountData, DutchEnglishData, Gating, LotData, SealedBidData,
+    SellerData, SoftClose, SoftCloseData, TieBreak, TimingData,
+};
 
 contractmeta!(
     key="desc",
@@ -25,21 +34,123 @@ contractmeta!(
 pub trait AuctionContractTrait {
     // Retrieves auction data, if it exists.
     // No authorization required.
-    fn get_auction(env: Env, seller: Address) -> Option<AuctionData>;
+    fn get_auction(env: Env, auction_id: u64) -> Option<AuctionData>;
 
-    // Resolves the auction, applying defined auction behavior and rules.
+    // Retrieves the IDs of the auctions started by the seller.
     // No authorization required.
-    fn resolve(env: Env, seller: Address);
+    fn get_auctions(env: Env, seller: Address) -> Vec<u64>;
+
+    // Resolves the auction, applying defined auction behavior and rules.
+    // No authorization required, except admin authorization for candle auctions.
+    fn resolve(env: Env, auction_id: u64);
 
     // Places a bid on an auction.
-    // Late bids (within anti_snipe_time from the end of the auction)
-    // are subject to anti-snipe rules and cannot be cancelled or modified.
+    // Late bids (within the soft-close window from the end of the auction,
+    // anti_snipe_time by default) are subject to anti-snipe rules
+    // and cannot be cancelled, only raised.
+    // Buyer authorization required.
+    fn place_bid(env: Env, auction_id: u64, buyer: Address, amount: i128);
+
+    // Raises an existing bid to the new amount, transferring only the difference.
+    // Late raises are subject to anti-snipe rules (see: place_bid).
+    // Not allowed on candle auctions, where a raise could land past the random cutoff.
     // Buyer authorization required.
-    fn place_bid(env: Env, seller: Address, buyer: Address, amount: i128);
+    fn raise_bid(env: Env, auction_id: u64, buyer: Address, amount: i128);
+
+    // Places a proxy bid on an ascending auction, escrowing the maximum amount.
+    // The bid is raised automatically only as much as needed to lead,
+    // and the escrow above the final bid is refunded at settlement.
+    // Buyer authorization required.
+    fn place_proxy_bid(env: Env, auction_id: u64, buyer: Address, maximum: i128);
+
+    // Places a bid for a quantity of units at a unit price on a multi-unit auction.
+    // The whole bid amount (quantity * unit_price) is escrowed, use place_bid
+    // with zero amount to cancel. On (variable rate) gradual Dutch auctions, units are bought
+    // immediately if the total price does not exceed the bid amount.
+    // Buyer authorization required.
+    fn place_unit_bid(env: Env, auction_id: u64, buyer: Address, quantity: i128, unit_price: i128);
+
+    // Buys a quantity of units from a fixed-price listing at the ask price per unit.
+    // The whole lot must be bought unless the listing allows partial purchases.
+    // Buyer authorization required.
+    fn buy(env: Env, auction_id: u64, buyer: Address, quantity: i128);
+
+    // Accepts the offer of a buyer on a make-offer listing, settling the sale
+    // at the offer amount and refunding all other offers.
+    // Seller authorization required.
+    fn accept_offer(env: Env, auction_id: u64, buyer: Address);
+
+    // Commits a sealed bid as sha256(auction_id || buyer || amount || salt) on a sealed-bid
+    // or Vickrey auction (see: compute_commitment). The deposit is escrowed and must cover
+    // the bid amount to be revealed, it is forfeited to the admin if never revealed.
+    // Buyer authorization required.
+    fn commit_bid(env: Env, auction_id: u64, buyer: Address, commitment: BytesN<32>, deposit: i128);
+
+    // Reveals a committed bid during the reveal phase of a sealed-bid or Vickrey auction.
+    // The deposit exceeding the bid amount is refunded.
+    // Buyer authorization required.
+    fn reveal_bid(env: Env, auction_id: u64, buyer: Address, amount: i128, salt: BytesN<32>);
+
+    // Sets the minimum increment by which new bids must exceed the best bid
+    // on an ascending auction. Only allowed before the auction opens (see: schedule).
+    // Seller authorization required.
+    fn set_bid_increment(env: Env, auction_id: u64, bid_increment: BidIncrement);
+
+    // Sets the tie-break policy of the auction (earliest bid wins, or ties rejected).
+    // Multi-unit auctions compare unit prices. Not allowed on sealed-bid, clock
+    // and fixed-price style auctions. Only allowed before the auction opens (see: schedule).
+    // Seller authorization required.
+    fn set_tie_break(env: Env, auction_id: u64, tie_break: TieBreak);
+
+    // Sets the bid cancellation policy of the auction (free retraction window,
+    // cancellation fee and irrevocability cutoff).
+    // Only allowed before the auction opens (see: schedule).
+    // Seller authorization required.
+    fn set_cancellation(env: Env, auction_id: u64, cancellation: CancellationData);
+
+    // Sets the soft-close policy of the auction: window (within the admin range),
+    // extension by the window or reset to now + window, and maximum extensions.
+    // Not allowed on auctions without anti-snipe extensions (e.g. candle, sealed-bid).
+    // Only allowed before the auction opens (see: schedule).
+    // Seller authorization required.
+    fn set_soft_close(env: Env, auction_id: u64, soft_close: SoftCloseData);
+
+    // Restricts participation to an allowlist of buyers or to holders of a minimum
+    // balance of a token (the admin default applies otherwise).
+    // Only allowed before the auction opens (see: schedule).
+    // Seller authorization required.
+    fn set_gating(env: Env, auction_id: u64, gating: Gating);
+
+    // Amends the reserve price, ask price, discount rate or price schedule of an auction.
+    // Reserve prices cannot be raised once bids are placed, and no amendments
+    // are allowed within the soft-close window.
+    // Seller authorization required.
+    fn amend(env: Env, auction_id: u64, amendment: Amendment);
 
     // Extends the duration of an ongoing auction.
     // Seller authorization required.
-    fn extend(env: Env, seller: Address, duration: u64) -> bool;
+    fn extend(env: Env, auction_id: u64, duration: u64) -> bool;
+
+    // Edits an auction before it opens, setting its start time, duration, prices, behavior
+    // and discount (with the same checks as when starting). The lot is escrowed again for the new behavior,
+    // and changing the behavior resets the per-auction bid increment, tie-break,
+    // cancellation and soft-close policies.
+    // Seller authorization required.
+    fn edit_scheduled(
+        env: Env,
+        auction_id: u64,
+        timing: TimingData,
+        reserve_price: i128,
+        ask_price: i128,
+        behavior: AuctionBehavior,
+        discount: DiscountData,
+    );
+
+    // Cancels an auction, returning the escrowed lot to the seller.
+    // Allowed before the auction opens or while there are no bids, and with bids
+    // only if enabled by the admin (see: set_cancel_with_bids), refunding every bidder.
+    // Seller authorization required.
+    fn cancel(env: Env, auction_id: u64);
 
     // One off. Initializes the contract settings post-deployment.
     // Admin authorization required.
@@ -51,7 +162,8 @@ pub trait AuctionContractTrait {
         extendable_auctions: bool,
     );
 
-    // Starts a new auction.
+    // Starts a new auction, returning its ID.
+    // Sellers can run several auctions concurrently.
     // Behaves as descending price auction if both discount_percent and discount_frequency have non-zero values.
     // The auction can be instantly won if a bidder meets or exceeds the ask_price,
     // provided it is set above the reserve price or discounted below the bid amount (for descending auctions).
@@ -68,10 +180,75 @@ pub trait AuctionContractTrait {
         discount_percent: u32,
         discount_frequency: u64,
         compounded_discount: bool,
-    );
+    ) -> u64;
+
+    // Starts a new auction with the given behavior (e.g. sealed-bid).
+    // Descending price auctions with discount rates must be started with `start`,
+    // custom price schedules use the DescendingPriceSchedule behavior.
+    // Seller authorization required.
+    fn start_with_behavior(
+        env: Env,
+        seller: Address,
+        token: Address,
+        amount: i128,
+        duration: u64,
+        market: Address,
+        reserve_price: i128,
+        ask_price: i128,
+        behavior: AuctionBehavior,
+    ) -> u64;
+
+    // Starts a new auction selling the lot together with a bundle of (token, amount) pairs,
+    // escrowed at start and delivered to the buyer of the whole lot.
+    // Bundles are not supported on auctions selling units separately.
+    // Seller authorization required.
+    fn start_bundle(
+        env: Env,
+        seller: Address,
+        token: Address,
+        amount: i128,
+        bundle: Vec<LotData>,
+        duration: u64,
+        market: Address,
+        reserve_price: i128,
+        ask_price: i128,
+        behavior: AuctionBehavior,
+    ) -> u64;
+
+    // Schedules a new auction opening at a future start_time, with the given behavior
+    // and bundle (see: start_bundle). The discount only applies to the DescendingPrice behavior.
+    // The lot is escrowed at once, bids are only accepted from start_time
+    // and the seller can edit or withdraw the auction until then.
+    // Seller authorization required.
+    fn schedule(
+        env: Env,
+        seller: Address,
+        token: Address,
+        amount: i128,
+        bundle: Vec<LotData>,
+        timing: TimingData,
+        market: Address,
+        reserve_price: i128,
+        ask_price: i128,
+        behavior: AuctionBehavior,
+        discount: DiscountData,
+    ) -> u64;
+
+    // Sets the allowed range (in seconds) for per-auction soft-close windows.
+    // Admin authorization required.
+    fn set_soft_close_range(env: Env, min_window: u64, max_window: u64);
+
+    // Sets the default participation gating applied to new auctions.
+    // Admin authorization required.
+    fn set_default_gating(env: Env, gating: Gating);
+
+    // Sets whether sellers can cancel auctions with bids (refunding every bidder).
+    // Admin authorization required.
+    fn set_cancel_with_bids(env: Env, allowed: bool);
 
-    // Notes: The Litemint marketplace implements an indirection mechanism for
-    // auction seller accounts. Learn more: https://blog.litemint.com/anatomy-of-a-stellar-powered-auction-on-litemint/
+    // Notes: Auctions are identified by contract-assigned IDs, so the indirection mechanism
+    // for auction seller accounts implemented by the Litemint marketplace to run concurrent
+    // auctions is no longer required. Learn more: https://blog.litemint.com/anatomy-of-a-stellar-powered-auction-on-litemint/
 }
 
 #[contract]
@@ -79,32 +256,419 @@ struct AuctionContract;
 
 #[contractimpl]
 impl AuctionContractTrait for AuctionContract {
-    fn get_auction(env: Env, seller: Address) -> Option<AuctionData> {
+    fn get_auction(env: Env, auction_id: u64) -> Option<AuctionData> {
         load_data_or_else::<DataKey, AuctionData, _, _>(
             &env,
-            &DataKey::AuctionData(seller),
+            &DataKey::AuctionData(auction_id),
             |opt| opt,
         )
     }
 
-    fn resolve(env: Env, seller: Address) {
+    fn get_auctions(env: Env, seller: Address) -> Vec<u64> {
+        load_data_or_else::<DataKey, SellerData, _, _>(&env, &DataKey::SellerData(seller), |opt| {
+            opt.map_or_else(|| vec![&env], |seller_data| seller_data.auctions)
+        })
+    }
+
+    fn resolve(env: Env, auction_id: u64) {
         let auction_data =
-            load_data::<DataKey, AuctionData>(&env, &DataKey::AuctionData(seller.clone()));
-        dispatcher!(auction_data.discount_percent > 0 && auction_data.discount_frequency > 0)
-            .resolve(&env, &seller);
+            load_data::<DataKey, AuctionData>(&env, &DataKey::AuctionData(auction_id));
+        if env.ledger().timestamp() >= auction_data.start_time {
+            dispatcher!(auction_data.behavior).resolve(&env, auction_id);
+        }
     }
 
-    fn place_bid(env: Env, seller: Address, buyer: Address, amount: i128) {
+    fn place_bid(env: Env, auction_id: u64, buyer: Address, amount: i128) {
         buyer.require_auth();
 
         let auction_data =
-            load_data::<DataKey, AuctionData>(&env, &DataKey::AuctionData(seller.clone()));
-        dispatcher!(auction_data.discount_percent > 0 && auction_data.discount_frequency > 0)
-            .manage_bid(&env, &seller, &buyer, amount);
+            load_data::<DataKey, AuctionData>(&env, &DataKey::AuctionData(auction_id));
+        require_started(&env, &auction_data);
+        if amount > 0 {
+            require_eligible(&env, &auction_data, &buyer);
+        }
+        dispatcher!(auction_data.behavior).manage_bid(
+            &env,
+            auction_id,
+            &buyer,
+            amount,
+            auction_data.amount,
+        );
+    }
+
+    fn raise_bid(env: Env, auction_id: u64, buyer: Address, amount: i128) {
+        buyer.require_auth();
+
+        let auction_data =
+            load_data::<DataKey, AuctionData>(&env, &DataKey::AuctionData(auction_id));
+        require_started(&env, &auction_data);
+        require_eligible(&env, &auction_data, &buyer);
+        match auction_data.behavior {
+            AuctionBehavior::AscendingPrice if has_proxy_bid(&env, auction_id, &buyer) => {
+                panic!("Not allowed to raise proxy bid.")
+            }
+            AuctionBehavior::AscendingPrice
+            | AuctionBehavior::DescendingPrice
+            | AuctionBehavior::DescendingPriceSchedule(_)
+            | AuctionBehavior::Offer
+            | AuctionBehavior::DutchEnglish(_) => {
+                dispatcher!(auction_data.behavior).raise_bid(&env, auction_id, &buyer, amount)
+            }
+            _ => panic!("Not allowed to raise bid."),
+        }
+    }
+
+    fn place_proxy_bid(env: Env, auction_id: u64, buyer: Address, maximum: i128) {
+        buyer.require_auth();
+
+        let auction_data =
+            load_data::<DataKey, AuctionData>(&env, &DataKey::AuctionData(auction_id));
+        require_started(&env, &auction_data);
+        require_eligible(&env, &auction_data, &buyer);
+        match auction_data.behavior {
+            AuctionBehavior::AscendingPrice => {
+                AscendingPriceAuction.place_proxy_bid(&env, auction_id, &buyer, maximum)
+            }
+            _ => panic!("Not an ascending auction."),
+        }
+    }
+
+    fn place_unit_bid(env: Env, auction_id: u64, buyer: Address, quantity: i128, unit_price: i128) {
+        buyer.require_auth();
+
+        let auction_data =
+            load_data::<DataKey, AuctionData>(&env, &DataKey::AuctionData(auction_id));
+        require_started(&env, &auction_data);
+        require_eligible(&env, &auction_data, &buyer);
+        match auction_data.behavior {
+            AuctionBehavior::UniformPrice
+            | AuctionBehavior::DiscriminatoryPrice
+            | AuctionBehavior::GradualDutch(_)
+            | AuctionBehavior::VariableRateGradualDutch(_) => {
+                dispatcher!(auction_data.behavior).manage_bid(
+                    &env,
+                    auction_id,
+                    &buyer,
+                    quantity
+                        .checked_mul(unit_price)
+                        .unwrap_or_else(|| panic!("Invalid bid amount.")),
+                    quantity,
+                )
+            }
+            _ => panic!("Not a multi-unit auction."),
+        }
+    }
+
+    fn buy(env: Env, auction_id: u64, buyer: Address, quantity: i128) {
+        buyer.require_auth();
+
+        let auction_data =
+            load_data::<DataKey, AuctionData>(&env, &DataKey::AuctionData(auction_id));
+        require_started(&env, &auction_data);
+        require_eligible(&env, &auction_data, &buyer);
+        match auction_data.behavior {
+            AuctionBehavior::FixedPrice(_) => dispatcher!(auction_data.behavior).manage_bid(
+                &env,
+                auction_id,
+                &buyer,
+                quantity
+                    .checked_mul(auction_data.ask_price)
+                    .unwrap_or_else(|| panic!("Invalid bid amount.")),
+                quantity,
+            ),
+            _ => panic!("Not a fixed-price listing."),
+        }
+    }
+
+    fn accept_offer(env: Env, auction_id: u64, buyer: Address) {
+        let auction_data =
+            load_data::<DataKey, AuctionData>(&env, &DataKey::AuctionData(auction_id));
+        auction_data.seller.require_auth();
+
+        require_started(&env, &auction_data);
+        match auction_data.behavior {
+            AuctionBehavior::Offer => OfferAuction.accept_offer(&env, auction_id, &buyer),
+            _ => panic!("Not a make-offer listing."),
+        }
     }
 
-    fn extend(env: Env, seller: Address, duration: u64) -> bool {
-        seller.require_auth();
+    fn commit_bid(env: Env, auction_id: u64, buyer: Address, commitment: BytesN<32>, deposit: i128) {
+        buyer.require_auth();
+
+        let auction_data =
+            load_data::<DataKey, AuctionData>(&env, &DataKey::AuctionData(auction_id));
+        require_started(&env, &auction_data);
+        require_eligible(&env, &auction_data, &buyer);
+        match auction_data.behavior {
+            AuctionBehavior::SealedBid(_) | AuctionBehavior::Vickrey(_) => {
+                SealedBidAuction.commit_bid(&env, auction_id, &buyer, &commitment, deposit)
+            }
+            _ => panic!("Not a sealed-bid auction."),
+        }
+    }
+
+    fn reveal_bid(env: Env, auction_id: u64, buyer: Address, amount: i128, salt: BytesN<32>) {
+        buyer.require_auth();
+
+        let auction_data =
+            load_data::<DataKey, AuctionData>(&env, &DataKey::AuctionData(auction_id));
+        require_started(&env, &auction_data);
+        match auction_data.behavior {
+            AuctionBehavior::SealedBid(_) | AuctionBehavior::Vickrey(_) => {
+                SealedBidAuction.reveal_bid(&env, auction_id, &buyer, amount, &salt)
+            }
+            _ => panic!("Not a sealed-bid auction."),
+        }
+    }
+
+    fn set_bid_increment(env: Env, auction_id: u64, bid_increment: BidIncrement) {
+        let mut auction_data =
+            load_data::<DataKey, AuctionData>(&env, &DataKey::AuctionData(auction_id));
+        auction_data.seller.require_auth();
+
+        match auction_data.behavior {
+            AuctionBehavior::AscendingPrice
+            | AuctionBehavior::Candle(_)
+            | AuctionBehavior::DutchEnglish(_) => {}
+            _ => panic!("Not an ascending auction."),
+        }
+
+        if env.ledger().timestamp() >= auction_data.start_time {
+            panic!("Not allowed to change bid increment.");
+        }
+
+        if !is_valid_increment(&bid_increment) {
+            panic!("Invalid auction parameters.");
+        }
+
+        auction_data.bid_increment = bid_increment;
+        save_data::<DataKey, AuctionData>(
+            &env,
+            &DataKey::AuctionData(auction_id),
+            &auction_data,
+        );
+    }
+
+    fn set_tie_break(env: Env, auction_id: u64, tie_break: TieBreak) {
+        let mut auction_data =
+            load_data::<DataKey, AuctionData>(&env, &DataKey::AuctionData(auction_id));
+        auction_data.seller.require_auth();
+
+        match auction_data.behavior {
+            AuctionBehavior::SealedBid(_)
+            | AuctionBehavior::Vickrey(_)
+            | AuctionBehavior::Clock(_)
+            | AuctionBehavior::GradualDutch(_)
+            | AuctionBehavior::VariableRateGradualDutch(_)
+            | AuctionBehavior::FixedPrice(_) => panic!("Not allowed to change tie-break."),
+            _ => {}
+        }
+
+        if env.ledger().timestamp() >= auction_data.start_time {
+            panic!("Not allowed to change tie-break.");
+        }
+
+        auction_data.tie_break = tie_break;
+        save_data::<DataKey, AuctionData>(
+            &env,
+            &DataKey::AuctionData(auction_id),
+            &auction_data,
+        );
+    }
+
+    fn set_cancellation(env: Env, auction_id: u64, cancellation: CancellationData) {
+        let mut auction_data =
+            load_data::<DataKey, AuctionData>(&env, &DataKey::AuctionData(auction_id));
+        auction_data.seller.require_auth();
+
+        match auction_data.behavior {
+            AuctionBehavior::Reverse | AuctionBehavior::Clock(_) => {
+                panic!("Not allowed to change cancellation.")
+            }
+            _ => {}
+        }
+
+        if env.ledger().timestamp() >= auction_data.start_time {
+            panic!("Not allowed to change cancellation.");
+        }
+
+        if cancellation.fee_percent > 100 {
+            panic!("Invalid auction parameters.");
+        }
+
+        auction_data.cancellation = CancellationPolicy::Restricted(cancellation);
+        save_data::<DataKey, AuctionData>(
+            &env,
+            &DataKey::AuctionData(auction_id),
+            &auction_data,
+        );
+    }
+
+    fn set_soft_close(env: Env, auction_id: u64, soft_close: SoftCloseData) {
+        let mut auction_data =
+            load_data::<DataKey, AuctionData>(&env, &DataKey::AuctionData(auction_id));
+        auction_data.seller.require_auth();
+
+        match auction_data.behavior {
+            AuctionBehavior::AscendingPrice
+            | AuctionBehavior::DescendingPrice
+            | AuctionBehavior::DescendingPriceSchedule(_)
+            | AuctionBehavior::UniformPrice
+            | AuctionBehavior::DiscriminatoryPrice
+            | AuctionBehavior::Reverse
+            | AuctionBehavior::DutchEnglish(_) => {}
+            _ => panic!("Not allowed to change soft-close."),
+        }
+
+        if env.ledger().timestamp() >= auction_data.start_time {
+            panic!("Not allowed to change soft-close.");
+        }
+
+        let admin_data = load_data::<DataKey, AdminData>(&env, &DataKey::AdminData);
+        if soft_close.window < admin_data.min_soft_close
+            || soft_close.window > admin_data.max_soft_close
+        {
+            panic!("Invalid auction parameters.");
+        }
+
+        auction_data.soft_close = SoftClose::Custom(SoftCloseData {
+            extensions: 0,
+            ..soft_close
+        });
+        save_data::<DataKey, AuctionData>(
+            &env,
+            &DataKey::AuctionData(auction_id),
+            &auction_data,
+        );
+    }
+
+    fn set_gating(env: Env, auction_id: u64, gating: Gating) {
+        let mut auction_data =
+            load_data::<DataKey, AuctionData>(&env, &DataKey::AuctionData(auction_id));
+        auction_data.seller.require_auth();
+
+        if env.ledger().timestamp() >= auction_data.start_time {
+            panic!("Not allowed to change gating.");
+        }
+
+        auction_data.gating = gating;
+        save_data::<DataKey, AuctionData>(
+            &env,
+            &DataKey::AuctionData(auction_id),
+            &auction_data,
+        );
+    }
+
+    fn amend(env: Env, auction_id: u64, amendment: Amendment) {
+        let auction_data =
+            load_data::<DataKey, AuctionData>(&env, &DataKey::AuctionData(auction_id));
+        auction_data.seller.require_auth();
+
+        let auction = dispatcher!(auction_data.behavior);
+        if env.ledger().timestamp() >= auction_data.start_time {
+            // First check that the auction is resolved.
+            if auction.resolve(&env, auction_id) {
+                return;
+            }
+
+            let (anti_snipe_time, _) = auction.anti_snipe(&env, &auction_data);
+            if env.ledger().timestamp()
+                >= (auction_data.start_time + auction_data.duration).saturating_sub(anti_snipe_time)
+            {
+                panic!("Not allowed to amend auction.");
+            }
+        }
+
+        let mut auction_data =
+            load_data::<DataKey, AuctionData>(&env, &DataKey::AuctionData(auction_id));
+        let previous = match (&amendment, &mut auction_data.behavior) {
+            (Amendment::ReservePrice(_), AuctionBehavior::Reverse) => {
+                panic!("Not allowed to amend auction.")
+            }
+            (Amendment::ReservePrice(reserve_price), _) => {
+                if *reserve_price < 0 {
+                    panic!("Invalid auction parameters.");
+                }
+
+                // Bidders are protected from reserve increases.
+                if *reserve_price > auction_data.reserve_price
+                    && (!auction_data.bids.is_empty()
+                        || has_data::<DataKey, SealedBidData>(
+                            &env,
+                            &DataKey::SealedBidData(auction_id),
+                        ))
+                {
+                    panic!("Not allowed to raise reserve price.");
+                }
+
+                let previous = Amendment::ReservePrice(auction_data.reserve_price);
+                auction_data.reserve_price = *reserve_price;
+                previous
+            }
+            (Amendment::AskPrice(ask_price), behavior) => {
+                if *ask_price < 0
+                    || (*ask_price == 0 && matches!(behavior, AuctionBehavior::FixedPrice(_)))
+                {
+                    panic!("Invalid auction parameters.");
+                }
+
+                let previous = Amendment::AskPrice(auction_data.ask_price);
+                auction_data.ask_price = *ask_price;
+                previous
+            }
+            (Amendment::Discount(discount), AuctionBehavior::DescendingPrice) => {
+                if !is_valid_discount(discount) {
+                    panic!("Invalid auction parameters.");
+                }
+
+                let previous = Amendment::Discount(DiscountData {
+                    discount_percent: auction_data.discount_percent,
+                    discount_frequency: auction_data.discount_frequency,
+                    compounded_discount: auction_data.compounded_discount,
+                });
+                auction_data.discount_percent = discount.discount_percent;
+                auction_data.discount_frequency = discount.discount_frequency;
+                auction_data.compounded_discount = discount.compounded_discount;
+                previous
+            }
+            (Amendment::Schedule(schedule), AuctionBehavior::DescendingPriceSchedule(current))
+            | (
+                Amendment::Schedule(schedule),
+                AuctionBehavior::DutchEnglish(DutchEnglishData {
+                    schedule: current,
+                    english: false,
+                    ..
+                }),
+            ) => {
+                if !is_valid_schedule(schedule) {
+                    panic!("Invalid auction parameters.");
+                }
+
+                Amendment::Schedule(core::mem::replace(current, schedule.clone()))
+            }
+            _ => panic!("Not allowed to amend auction."),
+        };
+
+        save_data::<DataKey, AuctionData>(
+            &env,
+            &DataKey::AuctionData(auction_id),
+            &auction_data,
+        );
+        env.events().publish(
+            (AUCTION, symbol_short!("amended")),
+            (auction_id, previous, amendment),
+        );
+
+        // Amended prices may be met by standing bids.
+        if env.ledger().timestamp() >= auction_data.start_time {
+            auction.resolve(&env, auction_id);
+        }
+    }
+
+    fn extend(env: Env, auction_id: u64, duration: u64) -> bool {
+        let mut auction_data =
+            load_data::<DataKey, AuctionData>(&env, &DataKey::AuctionData(auction_id));
+        auction_data.seller.require_auth();
 
         if !load_data_or_else::<DataKey, AdminData, _, _>(&env, &DataKey::AdminData, |opt| {
             opt.unwrap_or_else(|| panic!("Admin not set. Call initialize."))
@@ -113,18 +677,97 @@ impl AuctionContractTrait for AuctionContract {
         {
             false
         } else {
-            let mut auction_data =
-                load_data::<DataKey, AuctionData>(&env, &DataKey::AuctionData(seller.clone()));
             auction_data.duration += duration;
             save_data::<DataKey, AuctionData>(
                 &env,
-                &DataKey::AuctionData(seller.clone()),
+                &DataKey::AuctionData(auction_id),
                 &auction_data,
             );
             true
         }
     }
 
+    fn edit_scheduled(
+        env: Env,
+        auction_id: u64,
+        timing: TimingData,
+        reserve_price: i128,
+        ask_price: i128,
+        behavior: AuctionBehavior,
+        discount: DiscountData,
+    ) {
+        let previous =
+            load_data::<DataKey, AuctionData>(&env, &DataKey::AuctionData(auction_id));
+        previous.seller.require_auth();
+
+        if env.ledger().timestamp() >= previous.start_time {
+            panic!("Auction already started.");
+        }
+
+        if timing.start_time <= env.ledger().timestamp() || timing.duration == 0 {
+            panic!("Invalid auction parameters.");
+        }
+
+        let mut auction_data = AuctionData {
+            start_time: timing.start_time,
+            duration: timing.duration,
+            reserve_price,
+            ask_price,
+            discount_percent: discount.discount_percent,
+            discount_frequency: discount.discount_frequency,
+            compounded_discount: discount.compounded_discount,
+            behavior,
+            ..previous.clone()
+        };
+        if core::mem::discriminant(&auction_data.behavior)
+            != core::mem::discriminant(&previous.behavior)
+        {
+            auction_data.bid_increment = BidIncrement::None;
+            auction_data.tie_break = TieBreak::Earliest;
+            auction_data.cancellation = CancellationPolicy::Free;
+            auction_data.soft_close = SoftClose::Default;
+        }
+
+        let auction = dispatcher!(auction_data.behavior);
+        if !auction.accepts_parameters(&auction_data)
+            || (!auction_data.bundle.is_empty() && !auction.supports_bundle(&auction_data))
+        {
+            panic!("Invalid auction parameters.");
+        }
+
+        // Escrow the lot again according to the new parameters.
+        dispatcher!(previous.behavior).release_lot(&env, &previous);
+        auction.escrow_lot(&env, &auction_data);
+
+        save_data::<DataKey, AuctionData>(
+            &env,
+            &DataKey::AuctionData(auction_id),
+            &auction_data,
+        );
+        auction.bump_auction(&env, auction_id, &auction_data);
+    }
+
+    fn cancel(env: Env, auction_id: u64) {
+        let auction_data =
+            load_data::<DataKey, AuctionData>(&env, &DataKey::AuctionData(auction_id));
+        auction_data.seller.require_auth();
+
+        if env.ledger().timestamp() >= auction_data.start_time {
+            // First check that the auction is resolved.
+            if dispatcher!(auction_data.behavior).resolve(&env, auction_id) {
+                return;
+            }
+
+            let has_bids = !auction_data.bids.is_empty()
+                || has_data::<DataKey, SealedBidData>(&env, &DataKey::SealedBidData(auction_id));
+            if has_bids && !load_data::<DataKey, AdminData>(&env, &DataKey::AdminData).cancel_with_bids {
+                panic!("Not allowed to cancel auction.");
+            }
+        }
+
+        dispatcher!(auction_data.behavior).cancel(&env, auction_id);
+    }
+
     fn initialize(
         env: Env,
         admin: Address,
@@ -144,10 +787,53 @@ impl AuctionContractTrait for AuctionContract {
                 anti_snipe_time: anti_snipe_time.min(60),
                 commission_rate: commission_rate.max(0).min(100),
                 extendable_auctions,
+                default_gating: Gating::Open,
+                min_soft_close: 0,
+                max_soft_close: anti_snipe_time.min(60),
+                cancel_with_bids: false,
+                auction_counter: 0,
             },
         );
     }
 
+    fn set_soft_close_range(env: Env, min_window: u64, max_window: u64) {
+        let mut admin_data =
+            load_data_or_else::<DataKey, AdminData, _, _>(&env, &DataKey::AdminData, |opt| {
+                opt.unwrap_or_else(|| panic!("Admin not set. Call initialize."))
+            });
+        admin_data.admin.require_auth();
+
+        if min_window > max_window {
+            panic!("Invalid parameters.");
+        }
+
+        admin_data.min_soft_close = min_window;
+        admin_data.max_soft_close = max_window;
+        save_data::<DataKey, AdminData>(&env, &DataKey::AdminData, &admin_data);
+    }
+
+    fn set_default_gating(env: Env, gating: Gating) {
+        let mut admin_data =
+            load_data_or_else::<DataKey, AdminData, _, _>(&env, &DataKey::AdminData, |opt| {
+                opt.unwrap_or_else(|| panic!("Admin not set. Call initialize."))
+            });
+        admin_data.admin.require_auth();
+
+        admin_data.default_gating = gating;
+        save_data::<DataKey, AdminData>(&env, &DataKey::AdminData, &admin_data);
+    }
+
+    fn set_cancel_with_bids(env: Env, allowed: bool) {
+        let mut admin_data =
+            load_data_or_else::<DataKey, AdminData, _, _>(&env, &DataKey::AdminData, |opt| {
+                opt.unwrap_or_else(|| panic!("Admin not set. Call initialize."))
+            });
+        admin_data.admin.require_auth();
+
+        admin_data.cancel_with_bids = allowed;
+        save_data::<DataKey, AdminData>(&env, &DataKey::AdminData, &admin_data);
+    }
+
     fn start(
         env: Env,
         seller: Address,
@@ -160,21 +846,21 @@ impl AuctionContractTrait for AuctionContract {
         discount_percent: u32,
         discount_frequency: u64,
         compounded_discount: bool,
-    ) {
-        if !has_data::<DataKey, AdminData>(&env, &DataKey::AdminData) {
-            panic!("Admin not set. Call initialize.");
-        }
-
-        seller.require_auth();
-
+    ) -> u64 {
+        let behavior = if discount_percent > 0 && discount_frequency > 0 {
+            AuctionBehavior::DescendingPrice
+        } else {
+            AuctionBehavior::AscendingPrice
+        };
         let start_time = env.ledger().timestamp();
         let bids: Vec<BidData> = vec![&env];
-        dispatcher!(discount_percent > 0 && discount_frequency > 0).start(
+        start_auction(
             &env,
-            &seller,
             &AuctionData {
+                seller: seller.clone(),
                 token,
                 amount,
+                bundle: vec![&env],
                 duration,
                 start_time,
                 market,
@@ -183,10 +869,196 @@ impl AuctionContractTrait for AuctionContract {
                 discount_percent,
                 discount_frequency,
                 compounded_discount,
+                bid_increment: BidIncrement::None,
+                tie_break: TieBreak::Earliest,
+                cancellation: CancellationPolicy::Free,
+                gating: default_gating(&env),
+                soft_close: SoftClose::Default,
+                bid_sequence: 0,
+                behavior,
                 bids,
             },
         )
     }
+
+    fn start_with_behavior(
+        env: Env,
+        seller: Address,
+        token: Address,
+        amount: i128,
+        duration: u64,
+        market: Address,
+        reserve_price: i128,
+        ask_price: i128,
+        behavior: AuctionBehavior,
+    ) -> u64 {
+        Self::start_bundle(
+            env.clone(),
+            seller,
+            token,
+            amount,
+            vec![&env],
+            duration,
+            market,
+            reserve_price,
+            ask_price,
+            behavior,
+        )
+    }
+
+    fn start_bundle(
+        env: Env,
+        seller: Address,
+        token: Address,
+        amount: i128,
+        bundle: Vec<LotData>,
+        duration: u64,
+        market: Address,
+        reserve_price: i128,
+        ask_price: i128,
+        behavior: AuctionBehavior,
+    ) -> u64 {
+        let start_time = env.ledger().timestamp();
+        start_with_options(
+            &env,
+            &seller,
+            token,
+            amount,
+            bundle,
+            start_time,
+            duration,
+            market,
+            reserve_price,
+            ask_price,
+            behavior,
+            DiscountData {
+                discount_percent: 0,
+                discount_frequency: 0,
+                compounded_discount: false,
+            },
+        )
+    }
+
+    fn schedule(
+        env: Env,
+        seller: Address,
+        token: Address,
+        amount: i128,
+        bundle: Vec<LotData>,
+        timing: TimingData,
+        market: Address,
+        reserve_price: i128,
+        ask_price: i128,
+        behavior: AuctionBehavior,
+        discount: DiscountData,
+    ) -> u64 {
+        if timing.start_time <= env.ledger().timestamp() {
+            panic!("Invalid auction parameters.");
+        }
+
+        start_with_options(
+            &env,
+            &seller,
+            token,
+            amount,
+            bundle,
+            timing.start_time,
+            timing.duration,
+            market,
+            reserve_price,
+            ask_price,
+            behavior,
+            discount,
+        )
+    }
+}
+
+fn start_with_options(
+    env: &Env,
+    seller: &Address,
+    token: Address,
+    amount: i128,
+    bundle: Vec<LotData>,
+    start_time: u64,
+    duration: u64,
+    market: Address,
+    reserve_price: i128,
+    ask_price: i128,
+    behavior: AuctionBehavior,
+    discount: DiscountData,
+) -> u64 {
+    let bids: Vec<BidData> = vec![env];
+    start_auction(
+        env,
+        &AuctionData {
+            seller: seller.clone(),
+            token,
+            amount,
+            bundle,
+            duration,
+            start_time,
+            market,
+            reserve_price,
+            ask_price,
+            discount_percent: discount.discount_percent,
+            discount_frequency: discount.discount_frequency,
+            compounded_discount: discount.compounded_discount,
+            bid_increment: BidIncrement::None,
+            tie_break: TieBreak::Earliest,
+            cancellation: CancellationPolicy::Free,
+            gating: default_gating(env),
+            soft_close: SoftClose::Default,
+            bid_sequence: 0,
+            behavior,
+            bids,
+        },
+    )
+}
+
+// Marketplace-wide participation gating set by the admin.
+fn default_gating(env: &Env) -> Gating {
+    load_data_or_else::<DataKey, AdminData, _, _>(env, &DataKey::AdminData, |opt| {
+        opt.unwrap_or_else(|| panic!("Admin not set. Call initialize."))
+    })
+    .default_gating
+}
+
+// Checks that the auction is open for bidding.
+fn require_started(env: &Env, auction_data: &AuctionData) {
+    if env.ledger().timestamp() < auction_data.start_time {
+        panic!("Auction not started.");
+    }
+}
+
+// Checks that the buyer is allowed to bid on the auction.
+fn require_eligible(env: &Env, auction_data: &AuctionData, buyer: &Address) {
+    let eligible = match &auction_data.gating {
+        Gating::Open => true,
+        Gating::Allowlist(buyers) => buyers.contains(buyer),
+        Gating::TokenHolder(gate) => {
+            token::Client::new(env, &gate.token).balance(buyer) >= gate.min_balance
+        }
+    };
+    if !eligible {
+        panic!("Buyer not allowed.");
+    }
+}
+
+// Starts the auction with the next auction ID.
+fn start_auction(env: &Env, auction_data: &AuctionData) -> u64 {
+    let mut admin_data =
+        load_data_or_else::<DataKey, AdminData, _, _>(env, &DataKey::AdminData, |opt| {
+            opt.unwrap_or_else(|| panic!("Admin not set. Call initialize."))
+        });
+
+    auction_data.seller.require_auth();
+
+    let auction_id = admin_data.auction_counter;
+    admin_data.auction_counter += 1;
+    save_data::<DataKey, AdminData>(env, &DataKey::AdminData, &admin_data);
+
+    dispatcher!(auction_data.behavior).start(env, auction_id, auction_data);
+    auction_id
 }
 
 #[cfg(test)]
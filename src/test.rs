@@ -6,14 +6,26 @@
     MIT License
 */
 
-use crate::{impl_soroban_storage, storage::*, types::AuctionData, AuctionContract, AuctionContractClient};
+use crate::{
+    auctions::behavior_sealed_bid::compute_commitment,
+    impl_soroban_storage,
+    storage::*,
+    types::{
+        Amendment, AuctionBehavior, AuctionData, BidIncrement, BreakpointData, CancellationData, CancellationPolicy, Gating,
+        SoftClose, SoftCloseData, TokenGateData, ClockData, DiscountData, TimingData, DutchEnglishData, GradualDutchData,
+        IncrementTierData, LotData, TieBreak,
+        PriceScheduleData, VariableRateGradualDutchData,
+    },
+    AuctionContract, AuctionContractClient,
+};
 extern crate std;
 
 use core::panic::AssertUnwindSafe;
 use soroban_sdk::{
     contract, contractimpl, contracttype,
-    testutils::{Address as _, Logs},
-    token, vec, Address, Env,
+    symbol_short,
+    testutils::{Address as _, Events, Ledger, Logs},
+    token, vec, Address, BytesN, Env, IntoVal, TryIntoVal,
 };
 use std::panic::catch_unwind;
 use std::println;
@@ -27,6 +39,14 @@ fn create_token_contract<'a>(e: &Env, admin: &Address) -> (TokenClient<'a>, Toke
         TokenAdminClient::new(e, &contract_address),
     )
 }
+fn no_discount() -> DiscountData {
+    DiscountData {
+        discount_percent: 0,
+        discount_frequency: 0,
+        compounded_discount: false,
+    }
+}
+
 fn create_auction_contract(e: &Env) -> AuctionContractClient {
     AuctionContractClient::new(e, &e.register_contract(None, AuctionContract {}))
 }
@@ -36,7 +56,7 @@ fn start_auction(
     auction_contract: &AuctionContractClient,
     auction_data: &AuctionData,
     seller: &Address,
-) {
+) -> u64 {
     auction_contract.start(
         &seller,
         &auction_data.token,
@@ -48,7 +68,7 @@ fn start_auction(
         &auction_data.discount_percent,
         &auction_data.discount_frequency,
         &auction_data.compounded_discount,
-    );
+    )
 }
 
 #[test]
@@ -78,12 +98,14 @@ fn test_all() {
     auction_contract.initialize(&token_admin, &300, &commission_rate, &extendable_auctions);
 
     // No auction found should return None.
-    assert_eq!(auction_contract.get_auction(&seller), None);
+    assert_eq!(auction_contract.get_auction(&0), None);
 
     // Configure a descending price auction (Dutch auction).
     let mut auction_data: AuctionData = AuctionData {
+        seller: seller.clone(),
         token: token.address.clone(),
         amount: 1,
+        bundle: vec![&env],
         duration: 180,
         start_time: env.ledger().timestamp(),
         market: market.address.clone(),
@@ -92,14 +114,21 @@ fn test_all() {
         discount_percent: 10,
         discount_frequency: 20,
         compounded_discount: false,
+        bid_increment: BidIncrement::None,
+        tie_break: TieBreak::Earliest,
+        cancellation: CancellationPolicy::Free,
+        gating: Gating::Open,
+        soft_close: SoftClose::Default,
+        bid_sequence: 0,
+        behavior: AuctionBehavior::DescendingPrice,
         bids: vec![&env],
     };
 
     // Start the auction.
-    start_auction(&env, &auction_contract, &auction_data, &seller);
+    let auction_id = start_auction(&env, &auction_contract, &auction_data, &seller);
 
     // Should be matching all auction parameters.
-    let mut test_auction = auction_contract.get_auction(&seller);
+    let mut test_auction = auction_contract.get_auction(&auction_id);
     match test_auction {
         Some(test_auction) => {
             assert_eq!(test_auction, auction_data);
@@ -109,29 +138,29 @@ fn test_all() {
 
     // Placing a zero bid should panic if no existing bid to cancel.
     let mut result = catch_unwind(AssertUnwindSafe(|| {
-        auction_contract.place_bid(&seller, &bidders[0], &0);
+        auction_contract.place_bid(&auction_id, &bidders[0], &0);
     }));
     assert!(result.is_err(), "No bid to cancel.");
 
     // Placing a bid below reserve should always panic.
     result = catch_unwind(AssertUnwindSafe(|| {
-        auction_contract.place_bid(&seller, &bidders[0], &(auction_data.reserve_price - 1));
+        auction_contract.place_bid(&auction_id, &bidders[0], &(auction_data.reserve_price - 1));
     }));
     assert!(result.is_err(), "Invalid bid amount.");
 
     // Placing a bid at or above reserve.
-    auction_contract.place_bid(&seller, &bidders[0], &(auction_data.reserve_price));
+    auction_contract.place_bid(&auction_id, &bidders[0], &(auction_data.reserve_price));
 
     // Canceling the bid.
-    auction_contract.place_bid(&seller, &bidders[0], &0);
+    auction_contract.place_bid(&auction_id, &bidders[0], &0);
 
     // Placing a new bid (bidder 1).
-    auction_contract.place_bid(&seller, &bidders[0], &(auction_data.reserve_price + 1));
+    auction_contract.place_bid(&auction_id, &bidders[0], &(auction_data.reserve_price + 1));
 
     // Placing a new bid (bidder 2).
-    auction_contract.place_bid(&seller, &bidders[1], &(auction_data.reserve_price + 2));
+    auction_contract.place_bid(&auction_id, &bidders[1], &(auction_data.reserve_price + 2));
 
-    test_auction = auction_contract.get_auction(&seller);
+    test_auction = auction_contract.get_auction(&auction_id);
     match test_auction {
         Some(test_auction) => {
             // There should be 2 live bids at that point.
@@ -161,10 +190,10 @@ fn test_all() {
     // Try to resolve the auction.
     // The auction should still be running as no bidder
     // matched the ask_price.
-    auction_contract.resolve(&seller);
+    auction_contract.resolve(&auction_id);
 
     // Verify that no transfer occured.
-    test_auction = auction_contract.get_auction(&seller);
+    test_auction = auction_contract.get_auction(&auction_id);
     match test_auction {
         Some(test_auction) => {
             assert_eq!(test_auction.bids.len(), 2);
@@ -191,17 +220,17 @@ fn test_all() {
     }
 
     // Cancel the bids.
-    auction_contract.place_bid(&seller, &bidders[0], &0);
-    auction_contract.place_bid(&seller, &bidders[1], &0);
+    auction_contract.place_bid(&auction_id, &bidders[0], &0);
+    auction_contract.place_bid(&auction_id, &bidders[1], &0);
 
     // Submit a concurrent invalid bid.
-    auction_contract.place_bid(&seller, &bidders[0], &(auction_data.ask_price - 1));
+    auction_contract.place_bid(&auction_id, &bidders[0], &(auction_data.ask_price - 1));
 
     // Submit a winning bid.
-    auction_contract.place_bid(&seller, &bidders[1], &(auction_data.ask_price));
+    auction_contract.place_bid(&auction_id, &bidders[1], &(auction_data.ask_price));
 
     // Auction should have been resolved immediately.
-    test_auction = auction_contract.get_auction(&seller);
+    test_auction = auction_contract.get_auction(&auction_id);
     match test_auction {
         Some(_test_auction) => {
             assert!(false, "Auction should not be running.");
@@ -211,7 +240,7 @@ fn test_all() {
 
     // Try to resolve the auction (should have no effect).
     result = catch_unwind(AssertUnwindSafe(|| {
-        auction_contract.resolve(&seller);
+        auction_contract.resolve(&auction_id);
     }));
     assert!(result.is_err(), "No auction to resolve.");
 
@@ -237,13 +266,13 @@ fn test_all() {
     // Start an ascending price auction.
     auction_data.discount_percent = 0;
     auction_data.discount_frequency = 0;
-    start_auction(&env, &auction_contract, &auction_data, &seller);
+    let auction_id = start_auction(&env, &auction_contract, &auction_data, &seller);
 
     // Place a bid at ask price (buy now).
-    auction_contract.place_bid(&seller, &bidders[0], &(auction_data.ask_price));
+    auction_contract.place_bid(&auction_id, &bidders[0], &(auction_data.ask_price));
 
     // The auction should have resolved as ask price is met.
-    test_auction = auction_contract.get_auction(&seller);
+    test_auction = auction_contract.get_auction(&auction_id);
     match test_auction {
         Some(_test_auction) => {
             assert!(false, "Auction should not be running.");
@@ -253,7 +282,7 @@ fn test_all() {
 
     // Try to resolve the auction (should have no effect).
     result = catch_unwind(AssertUnwindSafe(|| {
-        auction_contract.resolve(&seller);
+        auction_contract.resolve(&auction_id);
     }));
     assert!(result.is_err(), "No auction to resolve.");
 
@@ -276,17 +305,17 @@ fn test_all() {
     assert_eq!(token.balance(&seller), token_supply - 2);
 
     // Start a new ascending price auction.
-    start_auction(&env, &auction_contract, &auction_data, &seller);
+    let auction_id = start_auction(&env, &auction_contract, &auction_data, &seller);
 
     // Place a bid at reserve price.
-    auction_contract.place_bid(&seller, &bidders[0], &(auction_data.reserve_price));
+    auction_contract.place_bid(&auction_id, &bidders[0], &(auction_data.reserve_price));
 
     // Auction should continue to run as the duration has not elapsed.
-    auction_contract.resolve(&seller);
+    auction_contract.resolve(&auction_id);
 
     // Extend the auction duration.
-    auction_contract.extend(&seller, &auction_data.duration);
-    test_auction = auction_contract.get_auction(&seller);
+    auction_contract.extend(&auction_id, &auction_data.duration);
+    test_auction = auction_contract.get_auction(&auction_id);
     match test_auction {
         Some(_test_auction) => {
             assert_eq!(_test_auction.duration, auction_data.duration * 2);
@@ -332,12 +361,14 @@ fn test_anti_sniping() {
     );
 
     // No auction found should return None.
-    assert_eq!(auction_contract.get_auction(&seller), None);
+    assert_eq!(auction_contract.get_auction(&0), None);
 
     // Configure a descending price auction (Dutch auction).
     let auction_data: AuctionData = AuctionData {
+        seller: seller.clone(),
         token: token.address.clone(),
         amount: 1,
+        bundle: vec![&env],
         duration: duration,
         start_time: env.ledger().timestamp(),
         market: market.address.clone(),
@@ -346,17 +377,24 @@ fn test_anti_sniping() {
         discount_percent: 10,
         discount_frequency: 20,
         compounded_discount: false,
+        bid_increment: BidIncrement::None,
+        tie_break: TieBreak::Earliest,
+        cancellation: CancellationPolicy::Free,
+        gating: Gating::Open,
+        soft_close: SoftClose::Default,
+        bid_sequence: 0,
+        behavior: AuctionBehavior::DescendingPrice,
         bids: vec![&env],
     };
 
     println!("START {}", auction_data.start_time);
 
     // Start the auction.
-    start_auction(&env, &auction_contract, &auction_data, &seller);
+    let auction_id = start_auction(&env, &auction_contract, &auction_data, &seller);
 
     // Submit a winning bid.
-    auction_contract.place_bid(&seller, &bidders[0], &(auction_data.reserve_price));
-    let test_auction = auction_contract.get_auction(&seller);
+    auction_contract.place_bid(&auction_id, &bidders[0], &(auction_data.reserve_price));
+    let test_auction = auction_contract.get_auction(&auction_id);
     match test_auction {
         Some(test_auction) => {
             // There should be 1 live bid at that point.
@@ -367,7 +405,7 @@ fn test_anti_sniping() {
 
             // Should not be able to cancel a sniper bid.
             let result = catch_unwind(AssertUnwindSafe(|| {
-                auction_contract.place_bid(&seller, &bidders[0], &0);
+                auction_contract.place_bid(&auction_id, &bidders[0], &0);
             }));
             assert!(result.is_err(), "Bid could be cancelled, should not.");
         }
@@ -375,6 +413,2335 @@ fn test_anti_sniping() {
     }
 }
 
+#[test]
+fn test_sealed_bid() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let initial_balance = 1000;
+    let commission_rate = 10;
+    let reveal_duration = 100;
+    let token_admin = Address::random(&env);
+    let seller = Address::random(&env);
+    let (token, token_admin_client) = create_token_contract(&env, &token_admin);
+    let (market, market_admin_client) = create_token_contract(&env, &token_admin);
+    let auction_contract = create_auction_contract(&env);
+    let bidders = [
+        Address::random(&env),
+        Address::random(&env),
+        Address::random(&env),
+    ];
+
+    // Initialize the balances.
+    token_admin_client.mint(&seller, &1);
+    for bidder in bidders.iter() {
+        market_admin_client.mint(&bidder, &initial_balance);
+    }
+
+    auction_contract.initialize(&token_admin, &60, &commission_rate, &true);
+
+    // Start a sealed-bid auction.
+    let reserve_price = 100;
+    let auction_id = auction_contract.start_with_behavior(
+        &seller,
+        &token.address,
+        &1,
+        &100,
+        &market.address,
+        &reserve_price,
+        &0,
+        &AuctionBehavior::SealedBid(reveal_duration),
+    );
+
+    // Open bids are not allowed.
+    let mut result = catch_unwind(AssertUnwindSafe(|| {
+        auction_contract.place_bid(&auction_id, &bidders[0], &reserve_price);
+    }));
+    assert!(result.is_err(), "Sealed bids must be committed.");
+
+    // Commit the bids, deposits hide the actual amounts.
+    let salts = [
+        BytesN::from_array(&env, &[1; 32]),
+        BytesN::from_array(&env, &[2; 32]),
+        BytesN::from_array(&env, &[3; 32]),
+    ];
+    let amounts = [300, 400, 500];
+    let deposits = [500, 400, 600];
+    for i in 0..bidders.len() {
+        auction_contract.commit_bid(
+            &auction_id,
+            &bidders[i],
+            &compute_commitment(&env, auction_id, &bidders[i], amounts[i], &salts[i]),
+            &deposits[i],
+        );
+        assert_eq!(market.balance(&bidders[i]), initial_balance - deposits[i]);
+    }
+
+    // Bids are not visible.
+    assert_eq!(auction_contract.get_auction(&auction_id).unwrap().bids.len(), 0);
+
+    // Revealing during the bidding phase should panic.
+    result = catch_unwind(AssertUnwindSafe(|| {
+        auction_contract.reveal_bid(&auction_id, &bidders[0], &amounts[0], &salts[0]);
+    }));
+    assert!(result.is_err(), "Not in reveal phase.");
+
+    // Enter the reveal phase.
+    env.ledger().with_mut(|li| li.timestamp = 101);
+
+    // Committing during the reveal phase should panic.
+    result = catch_unwind(AssertUnwindSafe(|| {
+        auction_contract.commit_bid(
+            &auction_id,
+            &Address::random(&env),
+            &compute_commitment(&env, auction_id, &bidders[0], amounts[0], &salts[0]),
+            &deposits[0],
+        );
+    }));
+    assert!(result.is_err(), "Bidding phase is over.");
+
+    // Mismatched reveal should panic.
+    result = catch_unwind(AssertUnwindSafe(|| {
+        auction_contract.reveal_bid(&auction_id, &bidders[0], &(amounts[0] + 1), &salts[0]);
+    }));
+    assert!(result.is_err(), "Invalid bid commitment.");
+
+    // Reveal bids 0 and 1, bidder 2 never reveals.
+    auction_contract.reveal_bid(&auction_id, &bidders[0], &amounts[0], &salts[0]);
+    auction_contract.reveal_bid(&auction_id, &bidders[1], &amounts[1], &salts[1]);
+    assert_eq!(market.balance(&bidders[0]), initial_balance - amounts[0]);
+    assert_eq!(market.balance(&bidders[1]), initial_balance - amounts[1]);
+    assert_eq!(auction_contract.get_auction(&auction_id).unwrap().bids.len(), 2);
+
+    // Auction should continue to run during the reveal phase.
+    auction_contract.resolve(&auction_id);
+    assert!(auction_contract.get_auction(&auction_id).is_some());
+
+    // Close the reveal phase and resolve.
+    env.ledger().with_mut(|li| li.timestamp = 202);
+    auction_contract.resolve(&auction_id);
+    assert_eq!(auction_contract.get_auction(&auction_id), None);
+
+    // Highest revealed bid wins, others are refunded and unrevealed deposits forfeited.
+    assert_eq!(token.balance(&bidders[1]), 1);
+    assert_eq!(market.balance(&bidders[1]), initial_balance - amounts[1]);
+    assert_eq!(market.balance(&bidders[0]), initial_balance);
+    assert_eq!(market.balance(&bidders[2]), initial_balance - deposits[2]);
+    assert_eq!(market.balance(&auction_contract.address), 0);
+    assert_eq!(
+        market.balance(&token_admin),
+        amounts[1] * commission_rate / 100 + deposits[2]
+    );
+    assert_eq!(
+        market.balance(&seller),
+        amounts[1] * (100 - commission_rate) / 100
+    );
+}
+
+#[test]
+fn test_vickrey() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let initial_balance = 1000;
+    let commission_rate = 10;
+    let token_admin = Address::random(&env);
+    let seller = Address::random(&env);
+    let (token, token_admin_client) = create_token_contract(&env, &token_admin);
+    let (market, market_admin_client) = create_token_contract(&env, &token_admin);
+    let auction_contract = create_auction_contract(&env);
+    let bidders = [Address::random(&env), Address::random(&env)];
+
+    // Initialize the balances.
+    token_admin_client.mint(&seller, &2);
+    for bidder in bidders.iter() {
+        market_admin_client.mint(&bidder, &initial_balance);
+    }
+
+    auction_contract.initialize(&token_admin, &60, &commission_rate, &true);
+
+    // Start a Vickrey auction.
+    let reserve_price = 100;
+    let behavior = AuctionBehavior::Vickrey(100);
+    let auction_id = auction_contract.start_with_behavior(
+        &seller,
+        &token.address,
+        &1,
+        &100,
+        &market.address,
+        &reserve_price,
+        &0,
+        &behavior,
+    );
+
+    let salt = BytesN::from_array(&env, &[7; 32]);
+    let amounts = [300, 450];
+    for i in 0..bidders.len() {
+        auction_contract.commit_bid(
+            &auction_id,
+            &bidders[i],
+            &compute_commitment(&env, auction_id, &bidders[i], amounts[i], &salt),
+            &amounts[i],
+        );
+    }
+
+    // Copying a rival commitment to set the second price.
+    let copier = Address::random(&env);
+    market_admin_client.mint(&copier, &initial_balance);
+    auction_contract.commit_bid(
+        &auction_id,
+        &copier,
+        &compute_commitment(&env, auction_id, &bidders[1], amounts[1], &salt),
+        &amounts[1],
+    );
+
+    // Reveal and resolve, the copied commitment cannot be revealed.
+    env.ledger().with_mut(|li| li.timestamp = 101);
+    for i in 0..bidders.len() {
+        auction_contract.reveal_bid(&auction_id, &bidders[i], &amounts[i], &salt);
+    }
+    let result = catch_unwind(AssertUnwindSafe(|| {
+        auction_contract.reveal_bid(&auction_id, &copier, &amounts[1], &salt);
+    }));
+    assert!(result.is_err(), "Invalid bid commitment.");
+    env.ledger().with_mut(|li| li.timestamp = 202);
+    auction_contract.resolve(&auction_id);
+    assert_eq!(auction_contract.get_auction(&auction_id), None);
+
+    // Highest bidder wins and pays the second-highest bid.
+    assert_eq!(token.balance(&bidders[1]), 1);
+    assert_eq!(market.balance(&bidders[1]), initial_balance - amounts[0]);
+    assert_eq!(market.balance(&bidders[0]), initial_balance);
+    assert_eq!(market.balance(&auction_contract.address), 0);
+    assert_eq!(market.balance(&copier), initial_balance - amounts[1]);
+    assert_eq!(
+        market.balance(&token_admin),
+        amounts[0] * commission_rate / 100 + amounts[1]
+    );
+    assert_eq!(
+        market.balance(&seller),
+        amounts[0] * (100 - commission_rate) / 100
+    );
+
+    // Single bid pays the reserve price.
+    let auction_id = auction_contract.start_with_behavior(
+        &seller,
+        &token.address,
+        &1,
+        &100,
+        &market.address,
+        &reserve_price,
+        &0,
+        &behavior,
+    );
+    auction_contract.commit_bid(
+        &auction_id,
+        &bidders[0],
+        &compute_commitment(&env, auction_id, &bidders[0], amounts[0], &salt),
+        &amounts[0],
+    );
+    env.ledger().with_mut(|li| li.timestamp = 303);
+    auction_contract.reveal_bid(&auction_id, &bidders[0], &amounts[0], &salt);
+    env.ledger().with_mut(|li| li.timestamp = 404);
+    auction_contract.resolve(&auction_id);
+    assert_eq!(token.balance(&bidders[0]), 1);
+    assert_eq!(market.balance(&bidders[0]), initial_balance - reserve_price);
+    assert_eq!(market.balance(&auction_contract.address), 0);
+}
+
+#[test]
+fn test_candle() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let initial_balance = 1000;
+    let commission_rate = 10;
+    let token_admin = Address::random(&env);
+    let seller = Address::random(&env);
+    let (token, token_admin_client) = create_token_contract(&env, &token_admin);
+    let (market, market_admin_client) = create_token_contract(&env, &token_admin);
+    let auction_contract = create_auction_contract(&env);
+    let bidders = [Address::random(&env), Address::random(&env)];
+
+    // Initialize the balances.
+    token_admin_client.mint(&seller, &1);
+    for bidder in bidders.iter() {
+        market_admin_client.mint(&bidder, &initial_balance);
+    }
+
+    auction_contract.initialize(&token_admin, &60, &commission_rate, &true);
+
+    // Start a candle auction with a 50 seconds candle window.
+    let duration = 100;
+    let reserve_price = 100;
+    let auction_id = auction_contract.start_with_behavior(
+        &seller,
+        &token.address,
+        &1,
+        &duration,
+        &market.address,
+        &reserve_price,
+        &0,
+        &AuctionBehavior::Candle(50),
+    );
+
+    // Place a bid before the candle window.
+    env.ledger().with_mut(|li| li.timestamp = 10);
+    auction_contract.place_bid(&auction_id, &bidders[0], &150);
+
+    // Place a bid within the candle window, which does not extend the auction.
+    env.ledger().with_mut(|li| li.timestamp = 60);
+    auction_contract.place_bid(&auction_id, &bidders[1], &200);
+    let test_auction = auction_contract.get_auction(&auction_id).unwrap();
+    assert_eq!(test_auction.duration, duration);
+    assert_eq!(test_auction.bids.get_unchecked(1).timestamp, 60);
+
+    // Bids are final during the candle window.
+    let result = catch_unwind(AssertUnwindSafe(|| {
+        auction_contract.place_bid(&auction_id, &bidders[0], &0);
+    }));
+    assert!(result.is_err(), "No bid to cancel.");
+
+    // Bids cannot be raised past a possible cutoff, the original bid still counts.
+    env.ledger().with_mut(|li| li.timestamp = 100);
+    let result = catch_unwind(AssertUnwindSafe(|| {
+        auction_contract.raise_bid(&auction_id, &bidders[0], &250);
+    }));
+    assert!(result.is_err(), "Not allowed to raise bid.");
+    assert_eq!(auction_contract.get_auction(&auction_id).unwrap().bids.get_unchecked(0).amount, 150);
+
+    // Resolve after the candle window.
+    env.ledger().with_mut(|li| li.timestamp = 101);
+    auction_contract.resolve(&auction_id);
+    assert_eq!(env.auths()[0].0, token_admin);
+    assert_eq!(auction_contract.get_auction(&auction_id), None);
+
+    // Retrieve the randomly drawn cutoff.
+    let cutoff_topics: soroban_sdk::Vec<soroban_sdk::Val> =
+        (symbol_short!("AUCTION"), symbol_short!("cutoff")).into_val(&env);
+    let (_, _, data) = env
+        .events()
+        .all()
+        .iter()
+        .filter(|(_, topics, _)| *topics == cutoff_topics)
+        .last()
+        .unwrap();
+    let (_, cutoff): (u64, u64) = data.try_into_val(&env).unwrap();
+    assert!((50..=100).contains(&cutoff));
+
+    // The highest bid placed before the cutoff wins.
+    let (winner, loser, price) = if cutoff >= 60 {
+        (&bidders[1], &bidders[0], 200)
+    } else {
+        (&bidders[0], &bidders[1], 150)
+    };
+    assert_eq!(token.balance(winner), 1);
+    assert_eq!(market.balance(winner), initial_balance - price);
+    assert_eq!(market.balance(loser), initial_balance);
+    assert_eq!(market.balance(&auction_contract.address), 0);
+}
+
+#[test]
+fn test_uniform_price() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let initial_balance = 1000;
+    let commission_rate = 10;
+    let token_supply: i128 = 10;
+    let token_admin = Address::random(&env);
+    let seller = Address::random(&env);
+    let (token, token_admin_client) = create_token_contract(&env, &token_admin);
+    let (market, market_admin_client) = create_token_contract(&env, &token_admin);
+    let auction_contract = create_auction_contract(&env);
+    let bidders = [
+        Address::random(&env),
+        Address::random(&env),
+        Address::random(&env),
+        Address::random(&env),
+    ];
+
+    // Initialize the balances.
+    token_admin_client.mint(&seller, &token_supply);
+    for bidder in bidders.iter() {
+        market_admin_client.mint(&bidder, &initial_balance);
+    }
+
+    auction_contract.initialize(&token_admin, &60, &commission_rate, &true);
+
+    // Start a multi-unit auction for the whole supply, reserve price is per unit.
+    let reserve_price = 10;
+    let auction_id = auction_contract.start_with_behavior(
+        &seller,
+        &token.address,
+        &token_supply,
+        &100,
+        &market.address,
+        &reserve_price,
+        &0,
+        &AuctionBehavior::UniformPrice,
+    );
+
+    // Bidding below the unit reserve price should panic.
+    let result = catch_unwind(AssertUnwindSafe(|| {
+        auction_contract.place_unit_bid(&auction_id, &bidders[0], &2, &(reserve_price - 1));
+    }));
+    assert!(result.is_err(), "Invalid bid amount.");
+
+    // Place unit bids (quantity, unit price).
+    let bids = [(4, 50), (5, 40), (4, 30), (2, 20)];
+    for i in 0..bidders.len() {
+        auction_contract.place_unit_bid(&auction_id, &bidders[i], &bids[i].0, &bids[i].1);
+        assert_eq!(
+            market.balance(&bidders[i]),
+            initial_balance - bids[i].0 * bids[i].1
+        );
+    }
+
+    env.ledger().with_mut(|li| li.timestamp = 101);
+    auction_contract.resolve(&auction_id);
+    assert_eq!(auction_contract.get_auction(&auction_id), None);
+
+    // Units go to the highest unit bids, bidder 2 is partially filled
+    // and every winner pays its lowest accepted unit price.
+    let clearing_price = 30;
+    let filled = [4, 5, 1, 0];
+    for i in 0..bidders.len() {
+        assert_eq!(token.balance(&bidders[i]), filled[i]);
+        assert_eq!(
+            market.balance(&bidders[i]),
+            initial_balance - filled[i] * clearing_price
+        );
+    }
+    assert_eq!(token.balance(&auction_contract.address), 0);
+    assert_eq!(market.balance(&auction_contract.address), 0);
+    assert_eq!(
+        market.balance(&token_admin),
+        token_supply * clearing_price * commission_rate / 100
+    );
+    assert_eq!(
+        market.balance(&seller),
+        token_supply * clearing_price * (100 - commission_rate) / 100
+    );
+}
+
+#[test]
+fn test_discriminatory_price() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let initial_balance = 1000;
+    let commission_rate = 10;
+    let token_supply: i128 = 10;
+    let token_admin = Address::random(&env);
+    let seller = Address::random(&env);
+    let (token, token_admin_client) = create_token_contract(&env, &token_admin);
+    let (market, market_admin_client) = create_token_contract(&env, &token_admin);
+    let auction_contract = create_auction_contract(&env);
+    let bidders = [
+        Address::random(&env),
+        Address::random(&env),
+        Address::random(&env),
+    ];
+
+    // Initialize the balances.
+    token_admin_client.mint(&seller, &token_supply);
+    for bidder in bidders.iter() {
+        market_admin_client.mint(&bidder, &initial_balance);
+    }
+
+    auction_contract.initialize(&token_admin, &60, &commission_rate, &true);
+
+    // Start a pay-as-bid auction for the whole supply.
+    let auction_id = auction_contract.start_with_behavior(
+        &seller,
+        &token.address,
+        &token_supply,
+        &100,
+        &market.address,
+        &10,
+        &0,
+        &AuctionBehavior::DiscriminatoryPrice,
+    );
+
+    // Place unit bids (quantity, unit price).
+    let bids = [(6, 30), (4, 50), (3, 40)];
+    for i in 0..bidders.len() {
+        auction_contract.place_unit_bid(&auction_id, &bidders[i], &bids[i].0, &bids[i].1);
+    }
+
+    env.ledger().with_mut(|li| li.timestamp = 101);
+    auction_contract.resolve(&auction_id);
+    assert_eq!(auction_contract.get_auction(&auction_id), None);
+
+    // Each winner pays its own unit price, bidder 0 is partially filled.
+    let filled = [3, 4, 3];
+    let mut proceeds = 0;
+    for i in 0..bidders.len() {
+        assert_eq!(token.balance(&bidders[i]), filled[i]);
+        assert_eq!(
+            market.balance(&bidders[i]),
+            initial_balance - filled[i] * bids[i].1
+        );
+        proceeds += filled[i] * bids[i].1;
+    }
+    assert_eq!(token.balance(&auction_contract.address), 0);
+    assert_eq!(market.balance(&auction_contract.address), 0);
+
+    // Commission is computed per fill.
+    let commission: i128 = (0..bidders.len())
+        .map(|i| filled[i] * bids[i].1 * commission_rate / 100)
+        .sum();
+    assert_eq!(market.balance(&token_admin), commission);
+    assert_eq!(market.balance(&seller), proceeds - commission);
+}
+
+#[test]
+fn test_reverse() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let budget = 500;
+    let quantity = 3;
+    let commission_rate = 10;
+    let token_admin = Address::random(&env);
+    let seller = Address::random(&env);
+    let (token, token_admin_client) = create_token_contract(&env, &token_admin);
+    let (market, market_admin_client) = create_token_contract(&env, &token_admin);
+    let auction_contract = create_auction_contract(&env);
+    let suppliers = [Address::random(&env), Address::random(&env)];
+
+    // Initialize the balances, the seller holds the budget and suppliers the token.
+    market_admin_client.mint(&seller, &budget);
+    for supplier in suppliers.iter() {
+        token_admin_client.mint(&supplier, &quantity);
+    }
+
+    auction_contract.initialize(&token_admin, &60, &commission_rate, &true);
+
+    // Start a reverse auction, escrowing the budget.
+    let auction_id = auction_contract.start_with_behavior(
+        &seller,
+        &token.address,
+        &quantity,
+        &100,
+        &market.address,
+        &budget,
+        &0,
+        &AuctionBehavior::Reverse,
+    );
+    assert_eq!(market.balance(&seller), 0);
+    assert_eq!(market.balance(&auction_contract.address), budget);
+
+    // Asks above the budget should panic.
+    let mut result = catch_unwind(AssertUnwindSafe(|| {
+        auction_contract.place_bid(&auction_id, &suppliers[0], &(budget + 1));
+    }));
+    assert!(result.is_err(), "Invalid bid amount.");
+
+    // Supplier 0 asks, escrowing the requested token amount.
+    auction_contract.place_bid(&auction_id, &suppliers[0], &400);
+    assert_eq!(token.balance(&suppliers[0]), 0);
+
+    // Asks must decrease.
+    result = catch_unwind(AssertUnwindSafe(|| {
+        auction_contract.place_bid(&auction_id, &suppliers[1], &450);
+    }));
+    assert!(result.is_err(), "Invalid bid amount.");
+    auction_contract.place_bid(&auction_id, &suppliers[1], &350);
+
+    env.ledger().with_mut(|li| li.timestamp = 101);
+    auction_contract.resolve(&auction_id);
+    assert_eq!(auction_contract.get_auction(&auction_id), None);
+
+    // Lowest ask wins, the seller receives the token and the budget difference.
+    assert_eq!(token.balance(&seller), quantity);
+    assert_eq!(market.balance(&seller), budget - 350);
+    assert_eq!(market.balance(&suppliers[1]), 350 * (100 - commission_rate) / 100);
+    assert_eq!(market.balance(&token_admin), 350 * commission_rate / 100);
+    assert_eq!(token.balance(&suppliers[1]), 0);
+    assert_eq!(token.balance(&suppliers[0]), quantity);
+    assert_eq!(market.balance(&suppliers[0]), 0);
+    assert_eq!(token.balance(&auction_contract.address), 0);
+    assert_eq!(market.balance(&auction_contract.address), 0);
+}
+
+#[test]
+fn test_clock() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let initial_balance = 1000;
+    let commission_rate = 10;
+    let token_admin = Address::random(&env);
+    let seller = Address::random(&env);
+    let (token, token_admin_client) = create_token_contract(&env, &token_admin);
+    let (market, market_admin_client) = create_token_contract(&env, &token_admin);
+    let auction_contract = create_auction_contract(&env);
+    let bidders = [
+        Address::random(&env),
+        Address::random(&env),
+        Address::random(&env),
+    ];
+
+    // Initialize the balances.
+    token_admin_client.mint(&seller, &1);
+    for bidder in bidders.iter() {
+        market_admin_client.mint(&bidder, &initial_balance);
+    }
+
+    auction_contract.initialize(&token_admin, &60, &commission_rate, &true);
+
+    // Clock must rise by a positive increment over non-empty rounds.
+    for clock_data in [
+        ClockData {
+            increment: 0,
+            round_duration: 10,
+        },
+        ClockData {
+            increment: 10,
+            round_duration: 0,
+        },
+    ] {
+        let result = catch_unwind(AssertUnwindSafe(|| {
+            auction_contract.start_with_behavior(
+                &seller,
+                &token.address,
+                &1,
+                &100,
+                &market.address,
+                &100,
+                &0,
+                &AuctionBehavior::Clock(clock_data.clone()),
+            );
+        }));
+        assert!(result.is_err(), "Invalid auction parameters.");
+    }
+
+    // Start a clock auction, price rises by 10 every 10 seconds from reserve.
+    let reserve_price = 100;
+    let auction_id = auction_contract.start_with_behavior(
+        &seller,
+        &token.address,
+        &1,
+        &100,
+        &market.address,
+        &reserve_price,
+        &0,
+        &AuctionBehavior::Clock(ClockData {
+            increment: 10,
+            round_duration: 10,
+        }),
+    );
+
+    // Round 0, all bidders enter at the reserve price.
+    for bidder in bidders.iter() {
+        auction_contract.place_bid(&auction_id, bidder, &reserve_price);
+    }
+
+    // Round 1, bidder 2 drops by not staying in.
+    env.ledger().with_mut(|li| li.timestamp = 12);
+    let mut result = catch_unwind(AssertUnwindSafe(|| {
+        auction_contract.place_bid(&auction_id, &bidders[0], &reserve_price);
+    }));
+    assert!(result.is_err(), "Invalid bid amount.");
+    auction_contract.place_bid(&auction_id, &bidders[0], &110);
+    auction_contract.place_bid(&auction_id, &bidders[1], &120);
+    assert_eq!(market.balance(&bidders[1]), initial_balance - 120);
+
+    // Round 2, bidder 1 drops.
+    env.ledger().with_mut(|li| li.timestamp = 25);
+    auction_contract.place_bid(&auction_id, &bidders[0], &120);
+
+    // Dropped bidders cannot re-enter.
+    result = catch_unwind(AssertUnwindSafe(|| {
+        auction_contract.place_bid(&auction_id, &bidders[2], &120);
+    }));
+    assert!(result.is_err(), "Not allowed to place new bid.");
+
+    // Round 2 is not completed.
+    auction_contract.resolve(&auction_id);
+    assert!(auction_contract.get_auction(&auction_id).is_some());
+
+    // Round 3, bidder 0 wins at the round 2 clock price.
+    env.ledger().with_mut(|li| li.timestamp = 31);
+    auction_contract.resolve(&auction_id);
+    assert_eq!(auction_contract.get_auction(&auction_id), None);
+    assert_eq!(token.balance(&bidders[0]), 1);
+    assert_eq!(market.balance(&bidders[0]), initial_balance - 120);
+    assert_eq!(market.balance(&bidders[1]), initial_balance);
+    assert_eq!(market.balance(&bidders[2]), initial_balance);
+    assert_eq!(market.balance(&auction_contract.address), 0);
+    assert_eq!(market.balance(&seller), 120 * (100 - commission_rate) / 100);
+
+    // Explicit drop-outs still set the decisive round.
+    let other_seller = Address::random(&env);
+    token_admin_client.mint(&other_seller, &1);
+    let other_auction_id = auction_contract.start_with_behavior(
+        &other_seller,
+        &token.address,
+        &1,
+        &100,
+        &market.address,
+        &reserve_price,
+        &0,
+        &AuctionBehavior::Clock(ClockData {
+            increment: 10,
+            round_duration: 10,
+        }),
+    );
+    env.ledger().with_mut(|li| li.timestamp = 35);
+    auction_contract.place_bid(&other_auction_id, &bidders[1], &reserve_price);
+    auction_contract.place_bid(&other_auction_id, &bidders[2], &reserve_price);
+    env.ledger().with_mut(|li| li.timestamp = 45);
+    auction_contract.place_bid(&other_auction_id, &bidders[1], &110);
+    auction_contract.place_bid(&other_auction_id, &bidders[2], &110);
+    env.ledger().with_mut(|li| li.timestamp = 55);
+    auction_contract.place_bid(&other_auction_id, &bidders[1], &120);
+    auction_contract.place_bid(&other_auction_id, &bidders[2], &0);
+    assert_eq!(market.balance(&bidders[2]), initial_balance);
+    result = catch_unwind(AssertUnwindSafe(|| {
+        auction_contract.place_bid(&other_auction_id, &bidders[2], &120);
+    }));
+    assert!(result.is_err(), "Not allowed to place new bid.");
+
+    // Bidder 1 wins at the clock price of the round it stayed in alone.
+    env.ledger().with_mut(|li| li.timestamp = 65);
+    auction_contract.resolve(&other_auction_id);
+    assert_eq!(auction_contract.get_auction(&other_auction_id), None);
+    assert_eq!(token.balance(&bidders[1]), 1);
+    assert_eq!(market.balance(&bidders[1]), initial_balance - 120);
+    assert_eq!(market.balance(&other_seller), 120 * (100 - commission_rate) / 100);
+}
+
+#[test]
+fn test_gradual_dutch() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let initial_balance = 10000;
+    let commission_rate = 10;
+    let token_supply: i128 = 6;
+    let token_admin = Address::random(&env);
+    let seller = Address::random(&env);
+    let (token, token_admin_client) = create_token_contract(&env, &token_admin);
+    let (market, market_admin_client) = create_token_contract(&env, &token_admin);
+    let auction_contract = create_auction_contract(&env);
+    let bidders = [Address::random(&env), Address::random(&env)];
+
+    // Initialize the balances.
+    token_admin_client.mint(&seller, &token_supply);
+    for bidder in bidders.iter() {
+        market_admin_client.mint(&bidder, &initial_balance);
+    }
+
+    auction_contract.initialize(&token_admin, &60, &commission_rate, &true);
+
+    // Units already sold or a scale factor not above one are rejected.
+    for gda_data in [
+        GradualDutchData {
+            decay_constant: 6_931_471_805_599_453,
+            scale_factor: 1_100_000_000_000_000_000,
+            sold: 1,
+        },
+        GradualDutchData {
+            decay_constant: 6_931_471_805_599_453,
+            scale_factor: 1_000_000_000_000_000_000,
+            sold: 0,
+        },
+    ] {
+        let result = catch_unwind(AssertUnwindSafe(|| {
+            auction_contract.start_with_behavior(
+                &seller,
+                &token.address,
+                &token_supply,
+                &1000,
+                &market.address,
+                &10,
+                &1000,
+                &AuctionBehavior::GradualDutch(gda_data.clone()),
+            );
+        }));
+        assert!(result.is_err(), "Invalid auction parameters.");
+    }
+
+    // A zero ask price would give every unit away.
+    let result = catch_unwind(AssertUnwindSafe(|| {
+        auction_contract.start_with_behavior(
+            &seller,
+            &token.address,
+            &token_supply,
+            &1000,
+            &market.address,
+            &0,
+            &0,
+            &AuctionBehavior::GradualDutch(GradualDutchData {
+                decay_constant: 6_931_471_805_599_453,
+                scale_factor: 1_100_000_000_000_000_000,
+                sold: 0,
+            }),
+        );
+    }));
+    assert!(result.is_err(), "Invalid auction parameters.");
+
+    // Start a GDA, price halves every 100 seconds and rises by 10% per unit sold.
+    let ask_price = 1000;
+    let auction_id = auction_contract.start_with_behavior(
+        &seller,
+        &token.address,
+        &token_supply,
+        &1000,
+        &market.address,
+        &10,
+        &ask_price,
+        &AuctionBehavior::GradualDutch(GradualDutchData {
+            decay_constant: 6_931_471_805_599_453,
+            scale_factor: 1_100_000_000_000_000_000,
+            sold: 0,
+        }),
+    );
+
+    // Bidding below the current price should panic.
+    let result = catch_unwind(AssertUnwindSafe(|| {
+        auction_contract.place_unit_bid(&auction_id, &bidders[0], &1, &(ask_price - 1));
+    }));
+    assert!(result.is_err(), "Invalid bid amount.");
+
+    // Buy 2 units at once, the second unit price is bumped by the first sale.
+    auction_contract.place_unit_bid(&auction_id, &bidders[0], &2, &1100);
+    assert_eq!(token.balance(&bidders[0]), 2);
+    assert_eq!(market.balance(&bidders[0]), initial_balance - 2100);
+    assert_eq!(market.balance(&seller), 2100 * (100 - commission_rate) / 100);
+
+    // Price decays by half after 100 seconds.
+    env.ledger().with_mut(|li| li.timestamp = 100);
+    auction_contract.place_unit_bid(&auction_id, &bidders[1], &1, &606);
+    let spent = initial_balance - market.balance(&bidders[1]);
+    assert!((605..=606).contains(&spent));
+    assert_eq!(token.balance(&bidders[1]), 1);
+
+    // Units priced below the reserve are floored, the rest follow the scale factor
+    // (about 9.5 floored to 10, then 10.45).
+    env.ledger().with_mut(|li| li.timestamp = 713);
+    let balance = market.balance(&bidders[1]);
+    auction_contract.place_unit_bid(&auction_id, &bidders[1], &2, &11);
+    assert_eq!(balance - market.balance(&bidders[1]), 21);
+    assert_eq!(token.balance(&bidders[1]), 3);
+    assert_eq!(market.balance(&auction_contract.address), 0);
+
+    // Unsold units are returned to the seller on expiry.
+    env.ledger().with_mut(|li| li.timestamp = 1001);
+    auction_contract.resolve(&auction_id);
+    assert_eq!(auction_contract.get_auction(&auction_id), None);
+    assert_eq!(token.balance(&seller), 1);
+    assert_eq!(token.balance(&auction_contract.address), 0);
+}
+
+#[test]
+fn test_variable_rate_gradual_dutch() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let initial_balance = 10000;
+    let commission_rate = 10;
+    let token_supply: i128 = 10;
+    let token_admin = Address::random(&env);
+    let seller = Address::random(&env);
+    let (token, token_admin_client) = create_token_contract(&env, &token_admin);
+    let (market, market_admin_client) = create_token_contract(&env, &token_admin);
+    let auction_contract = create_auction_contract(&env);
+    let bidder = Address::random(&env);
+
+    // Initialize the balances.
+    token_admin_client.mint(&seller, &token_supply);
+    market_admin_client.mint(&bidder, &initial_balance);
+
+    auction_contract.initialize(&token_admin, &60, &commission_rate, &true);
+
+    // Units already sold or no issuance rate are rejected.
+    for vrgda_data in [
+        VariableRateGradualDutchData {
+            decay_constant: 6_931_471_805_599_453,
+            units_per_second: 100_000_000_000_000_000,
+            sold: 1,
+        },
+        VariableRateGradualDutchData {
+            decay_constant: 6_931_471_805_599_453,
+            units_per_second: 0,
+            sold: 0,
+        },
+    ] {
+        let result = catch_unwind(AssertUnwindSafe(|| {
+            auction_contract.start_with_behavior(
+                &seller,
+                &token.address,
+                &token_supply,
+                &1000,
+                &market.address,
+                &10,
+                &1000,
+                &AuctionBehavior::VariableRateGradualDutch(vrgda_data.clone()),
+            );
+        }));
+        assert!(result.is_err(), "Invalid auction parameters.");
+    }
+
+    // A zero target price would give every unit away.
+    let result = catch_unwind(AssertUnwindSafe(|| {
+        auction_contract.start_with_behavior(
+            &seller,
+            &token.address,
+            &token_supply,
+            &1000,
+            &market.address,
+            &0,
+            &0,
+            &AuctionBehavior::VariableRateGradualDutch(VariableRateGradualDutchData {
+                decay_constant: 6_931_471_805_599_453,
+                units_per_second: 100_000_000_000_000_000,
+                sold: 0,
+            }),
+        );
+    }));
+    assert!(result.is_err(), "Invalid auction parameters.");
+
+    // Start a VRGDA targeting 1 unit every 10 seconds at 1000,
+    // price halves for every 100 seconds behind schedule.
+    let target_price = 1000;
+    let auction_id = auction_contract.start_with_behavior(
+        &seller,
+        &token.address,
+        &token_supply,
+        &1000,
+        &market.address,
+        &10,
+        &target_price,
+        &AuctionBehavior::VariableRateGradualDutch(VariableRateGradualDutchData {
+            decay_constant: 6_931_471_805_599_453,
+            units_per_second: 100_000_000_000_000_000,
+            sold: 0,
+        }),
+    );
+
+    // First unit is on schedule and sells at the target price.
+    auction_contract.place_unit_bid(&auction_id, &bidder, &1, &target_price);
+    assert_eq!(market.balance(&bidder), initial_balance - target_price);
+
+    // Sales ahead of schedule raise the price.
+    let result = catch_unwind(AssertUnwindSafe(|| {
+        auction_contract.place_unit_bid(&auction_id, &bidder, &1, &target_price);
+    }));
+    assert!(result.is_err(), "Invalid bid amount.");
+
+    // Sales behind schedule lower the price (40 seconds behind).
+    env.ledger().with_mut(|li| li.timestamp = 50);
+    auction_contract.place_unit_bid(&auction_id, &bidder, &1, &target_price);
+    let spent = initial_balance - target_price - market.balance(&bidder);
+    assert!((757..=759).contains(&spent));
+    assert_eq!(token.balance(&bidder), 2);
+
+    // Units bought at once are priced along the schedule (30, 20 and 10 seconds behind).
+    let balance = market.balance(&bidder);
+    auction_contract.place_unit_bid(&auction_id, &bidder, &3, &target_price);
+    let spent = balance - market.balance(&bidder);
+    assert!((2615..=2617).contains(&spent));
+    assert_eq!(token.balance(&bidder), 5);
+
+    let test_auction = auction_contract.get_auction(&auction_id).unwrap();
+    assert_eq!(test_auction.amount, token_supply - 5);
+}
+
+#[test]
+fn test_fixed_price() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let initial_balance = 10000;
+    let commission_rate = 10;
+    let token_supply: i128 = 10;
+    let token_admin = Address::random(&env);
+    let seller = Address::random(&env);
+    let other_seller = Address::random(&env);
+    let (token, token_admin_client) = create_token_contract(&env, &token_admin);
+    let (market, market_admin_client) = create_token_contract(&env, &token_admin);
+    let auction_contract = create_auction_contract(&env);
+    let bidder = Address::random(&env);
+
+    // Initialize the balances.
+    token_admin_client.mint(&seller, &token_supply);
+    token_admin_client.mint(&other_seller, &token_supply);
+    market_admin_client.mint(&bidder, &initial_balance);
+
+    auction_contract.initialize(&token_admin, &60, &commission_rate, &true);
+
+    // Listing selling units at 100 each with partial purchases allowed.
+    let unit_price = 100;
+    let auction_id = auction_contract.start_with_behavior(
+        &seller,
+        &token.address,
+        &token_supply,
+        &1000,
+        &market.address,
+        &0,
+        &unit_price,
+        &AuctionBehavior::FixedPrice(true),
+    );
+
+    auction_contract.buy(&auction_id, &bidder, &3);
+    assert_eq!(token.balance(&bidder), 3);
+    assert_eq!(market.balance(&bidder), initial_balance - 3 * unit_price);
+    assert_eq!(market.balance(&seller), 3 * unit_price * (100 - commission_rate) / 100);
+    assert_eq!(market.balance(&token_admin), 3 * unit_price * commission_rate / 100);
+    assert_eq!(
+        auction_contract.get_auction(&auction_id).unwrap().amount,
+        token_supply - 3
+    );
+
+    // Buying the remaining units ends the listing.
+    auction_contract.buy(&auction_id, &bidder, &(token_supply - 3));
+    assert_eq!(token.balance(&bidder), token_supply);
+    assert!(auction_contract.get_auction(&auction_id).is_none());
+
+    // Listing selling the whole lot only.
+    let other_auction_id = auction_contract.start_with_behavior(
+        &other_seller,
+        &token.address,
+        &token_supply,
+        &1000,
+        &market.address,
+        &0,
+        &unit_price,
+        &AuctionBehavior::FixedPrice(false),
+    );
+
+    let result = catch_unwind(AssertUnwindSafe(|| {
+        auction_contract.buy(&other_auction_id, &bidder, &1);
+    }));
+    assert!(result.is_err(), "Partial purchases not allowed.");
+
+    // Unsold lot is returned to the seller on expiry.
+    env.ledger().with_mut(|li| li.timestamp = 1001);
+    auction_contract.resolve(&other_auction_id);
+    assert!(auction_contract.get_auction(&other_auction_id).is_none());
+    assert_eq!(token.balance(&other_seller), token_supply);
+}
+
+#[test]
+fn test_offer() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let initial_balance = 10000;
+    let commission_rate = 10;
+    let token_admin = Address::random(&env);
+    let seller = Address::random(&env);
+    let (token, token_admin_client) = create_token_contract(&env, &token_admin);
+    let (market, market_admin_client) = create_token_contract(&env, &token_admin);
+    let auction_contract = create_auction_contract(&env);
+    let bidders = [
+        Address::random(&env),
+        Address::random(&env),
+        Address::random(&env),
+    ];
+
+    // Initialize the balances.
+    token_admin_client.mint(&seller, &1);
+    for bidder in bidders.iter() {
+        market_admin_client.mint(bidder, &initial_balance);
+    }
+
+    auction_contract.initialize(&token_admin, &60, &commission_rate, &true);
+    let auction_id = auction_contract.start_with_behavior(
+        &seller,
+        &token.address,
+        &1,
+        &1000,
+        &market.address,
+        &100,
+        &0,
+        &AuctionBehavior::Offer,
+    );
+
+    // Offers below the reserve are rejected.
+    let result = catch_unwind(AssertUnwindSafe(|| {
+        auction_contract.place_bid(&auction_id, &bidders[0], &50);
+    }));
+    assert!(result.is_err(), "Invalid bid amount.");
+
+    auction_contract.place_bid(&auction_id, &bidders[0], &500);
+    auction_contract.place_bid(&auction_id, &bidders[1], &300);
+    auction_contract.place_bid(&auction_id, &bidders[2], &800);
+
+    // Offers can be withdrawn until accepted.
+    auction_contract.place_bid(&auction_id, &bidders[2], &0);
+    assert_eq!(market.balance(&bidders[2]), initial_balance);
+
+    let result = catch_unwind(AssertUnwindSafe(|| {
+        auction_contract.accept_offer(&auction_id, &bidders[2]);
+    }));
+    assert!(result.is_err(), "No offer to accept.");
+
+    // Seller picks a lower offer than the best one.
+    auction_contract.accept_offer(&auction_id, &bidders[1]);
+    assert!(auction_contract.get_auction(&auction_id).is_none());
+    assert_eq!(token.balance(&bidders[1]), 1);
+    assert_eq!(market.balance(&bidders[1]), initial_balance - 300);
+    assert_eq!(market.balance(&bidders[0]), initial_balance);
+    assert_eq!(market.balance(&seller), 300 * (100 - commission_rate) / 100);
+    assert_eq!(market.balance(&token_admin), 300 * commission_rate / 100);
+}
+
+#[test]
+fn test_bundle() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let initial_balance = 10000;
+    let commission_rate = 10;
+    let token_admin = Address::random(&env);
+    let seller = Address::random(&env);
+    let (token, token_admin_client) = create_token_contract(&env, &token_admin);
+    let (utility, utility_admin_client) = create_token_contract(&env, &token_admin);
+    let (market, market_admin_client) = create_token_contract(&env, &token_admin);
+    let auction_contract = create_auction_contract(&env);
+    let bidders = [Address::random(&env), Address::random(&env)];
+
+    // Initialize the balances.
+    token_admin_client.mint(&seller, &1);
+    utility_admin_client.mint(&seller, &50);
+    for bidder in bidders.iter() {
+        market_admin_client.mint(bidder, &initial_balance);
+    }
+
+    auction_contract.initialize(&token_admin, &60, &commission_rate, &true);
+
+    let bundle = vec![
+        &env,
+        LotData {
+            token: utility.address.clone(),
+            amount: 50,
+        },
+    ];
+
+    // Bundles are not supported when units are sold separately.
+    let result = catch_unwind(AssertUnwindSafe(|| {
+        auction_contract.start_bundle(
+            &seller,
+            &token.address,
+            &1,
+            &bundle,
+            &1000,
+            &market.address,
+            &100,
+            &0,
+            &AuctionBehavior::UniformPrice,
+        );
+    }));
+    assert!(result.is_err(), "Invalid auction parameters.");
+
+    // NFT sold together with its companion utility tokens.
+    let auction_id = auction_contract.start_bundle(
+        &seller,
+        &token.address,
+        &1,
+        &bundle,
+        &1000,
+        &market.address,
+        &100,
+        &0,
+        &AuctionBehavior::AscendingPrice,
+    );
+    assert_eq!(token.balance(&seller), 0);
+    assert_eq!(utility.balance(&seller), 0);
+    assert_eq!(auction_contract.get_auction(&auction_id).unwrap().bundle, bundle);
+
+    auction_contract.place_bid(&auction_id, &bidders[0], &200);
+    auction_contract.place_bid(&auction_id, &bidders[1], &300);
+
+    env.ledger().with_mut(|li| li.timestamp = 1001);
+    auction_contract.resolve(&auction_id);
+    assert!(auction_contract.get_auction(&auction_id).is_none());
+    assert_eq!(token.balance(&bidders[1]), 1);
+    assert_eq!(utility.balance(&bidders[1]), 50);
+    assert_eq!(utility.balance(&bidders[0]), 0);
+    assert_eq!(market.balance(&bidders[0]), initial_balance);
+    assert_eq!(market.balance(&seller), 300 * (100 - commission_rate) / 100);
+
+    // Bundle is returned to the seller when the lot does not sell.
+    token_admin_client.mint(&seller, &1);
+    utility_admin_client.mint(&seller, &50);
+    let auction_id = auction_contract.start_bundle(
+        &seller,
+        &token.address,
+        &1,
+        &bundle,
+        &1000,
+        &market.address,
+        &100,
+        &0,
+        &AuctionBehavior::AscendingPrice,
+    );
+    env.ledger().with_mut(|li| li.timestamp = 2002);
+    auction_contract.resolve(&auction_id);
+    assert_eq!(token.balance(&seller), 1);
+    assert_eq!(utility.balance(&seller), 50);
+}
+
+#[test]
+fn test_price_schedule() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let initial_balance = 10000;
+    let commission_rate = 10;
+    let token_admin = Address::random(&env);
+    let seller = Address::random(&env);
+    let other_seller = Address::random(&env);
+    let (token, token_admin_client) = create_token_contract(&env, &token_admin);
+    let (market, market_admin_client) = create_token_contract(&env, &token_admin);
+    let auction_contract = create_auction_contract(&env);
+    let bidder = Address::random(&env);
+
+    // Initialize the balances.
+    token_admin_client.mint(&seller, &1);
+    token_admin_client.mint(&other_seller, &1);
+    market_admin_client.mint(&bidder, &initial_balance);
+
+    auction_contract.initialize(&token_admin, &60, &commission_rate, &true);
+
+    // Fast drop for the first 100 seconds, then flat.
+    let breakpoints = vec![
+        &env,
+        BreakpointData {
+            offset: 0,
+            price: 1000,
+        },
+        BreakpointData {
+            offset: 100,
+            price: 200,
+        },
+    ];
+
+    // Schedules must start at offset 0 with non-increasing prices.
+    let result = catch_unwind(AssertUnwindSafe(|| {
+        auction_contract.start_with_behavior(
+            &seller,
+            &token.address,
+            &1,
+            &1000,
+            &market.address,
+            &100,
+            &0,
+            &AuctionBehavior::DescendingPriceSchedule(PriceScheduleData {
+                breakpoints: vec![
+                    &env,
+                    BreakpointData {
+                        offset: 0,
+                        price: 200,
+                    },
+                    BreakpointData {
+                        offset: 100,
+                        price: 1000,
+                    },
+                ],
+                linear: true,
+            }),
+        );
+    }));
+    assert!(result.is_err(), "Invalid auction parameters.");
+
+    let auction_id = auction_contract.start_with_behavior(
+        &seller,
+        &token.address,
+        &1,
+        &1000,
+        &market.address,
+        &100,
+        &0,
+        &AuctionBehavior::DescendingPriceSchedule(PriceScheduleData {
+            breakpoints: breakpoints.clone(),
+            linear: true,
+        }),
+    );
+    let other_auction_id = auction_contract.start_with_behavior(
+        &other_seller,
+        &token.address,
+        &1,
+        &1000,
+        &market.address,
+        &100,
+        &0,
+        &AuctionBehavior::DescendingPriceSchedule(PriceScheduleData {
+            breakpoints,
+            linear: false,
+        }),
+    );
+
+    // Linear schedule price is 600 at 50 seconds.
+    env.ledger().with_mut(|li| li.timestamp = 50);
+    auction_contract.place_bid(&auction_id, &bidder, &500);
+    assert!(auction_contract.get_auction(&auction_id).is_some());
+
+    // Linear schedule price is 496 at 63 seconds.
+    env.ledger().with_mut(|li| li.timestamp = 63);
+    auction_contract.resolve(&auction_id);
+    assert!(auction_contract.get_auction(&auction_id).is_none());
+    assert_eq!(token.balance(&bidder), 1);
+
+    // Step schedule holds 1000 until 100 seconds, then 200.
+    env.ledger().with_mut(|li| li.timestamp = 99);
+    auction_contract.place_bid(&other_auction_id, &bidder, &200);
+    assert!(auction_contract.get_auction(&other_auction_id).is_some());
+    env.ledger().with_mut(|li| li.timestamp = 100);
+    auction_contract.resolve(&other_auction_id);
+    assert!(auction_contract.get_auction(&other_auction_id).is_none());
+    assert_eq!(token.balance(&bidder), 2);
+    assert_eq!(market.balance(&bidder), initial_balance - 500 - 200);
+}
+
+#[test]
+fn test_dutch_english() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let initial_balance = 10000;
+    let commission_rate = 10;
+    let token_admin = Address::random(&env);
+    let seller = Address::random(&env);
+    let (token, token_admin_client) = create_token_contract(&env, &token_admin);
+    let (market, market_admin_client) = create_token_contract(&env, &token_admin);
+    let auction_contract = create_auction_contract(&env);
+    let bidders = [Address::random(&env), Address::random(&env)];
+
+    // Initialize the balances.
+    token_admin_client.mint(&seller, &1);
+    for bidder in bidders.iter() {
+        market_admin_client.mint(bidder, &initial_balance);
+    }
+
+    auction_contract.initialize(&token_admin, &10, &commission_rate, &true);
+    let auction_id = auction_contract.start_with_behavior(
+        &seller,
+        &token.address,
+        &1,
+        &1000,
+        &market.address,
+        &100,
+        &0,
+        &AuctionBehavior::DutchEnglish(DutchEnglishData {
+            schedule: PriceScheduleData {
+                breakpoints: vec![
+                    &env,
+                    BreakpointData {
+                        offset: 0,
+                        price: 1000,
+                    },
+                    BreakpointData {
+                        offset: 100,
+                        price: 200,
+                    },
+                ],
+                linear: true,
+            },
+            bidding_window: 60,
+            english: false,
+        }),
+    );
+
+    // Descending price is 600 at 50 seconds, the bid opens the bidding window.
+    env.ledger().with_mut(|li| li.timestamp = 50);
+    auction_contract.place_bid(&auction_id, &bidders[0], &600);
+    let test_auction = auction_contract.get_auction(&auction_id).unwrap();
+    assert_eq!(test_auction.duration, 110);
+    match test_auction.behavior {
+        AuctionBehavior::DutchEnglish(data) => assert!(data.english),
+        _ => panic!(),
+    }
+
+    // Bids must now outbid the best bid.
+    env.ledger().with_mut(|li| li.timestamp = 70);
+    let result = catch_unwind(AssertUnwindSafe(|| {
+        auction_contract.place_bid(&auction_id, &bidders[1], &550);
+    }));
+    assert!(result.is_err(), "Invalid bid amount.");
+    auction_contract.place_bid(&auction_id, &bidders[1], &700);
+
+    // Window is over, the highest bid wins.
+    env.ledger().with_mut(|li| li.timestamp = 111);
+    auction_contract.resolve(&auction_id);
+    assert!(auction_contract.get_auction(&auction_id).is_none());
+    assert_eq!(token.balance(&bidders[1]), 1);
+    assert_eq!(market.balance(&bidders[1]), initial_balance - 700);
+    assert_eq!(market.balance(&bidders[0]), initial_balance);
+    assert_eq!(market.balance(&seller), 700 * (100 - commission_rate) / 100);
+}
+
+#[test]
+fn test_bid_increment() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let initial_balance = 10000;
+    let commission_rate = 10;
+    let token_admin = Address::random(&env);
+    let seller = Address::random(&env);
+    let other_seller = Address::random(&env);
+    let (token, token_admin_client) = create_token_contract(&env, &token_admin);
+    let (market, market_admin_client) = create_token_contract(&env, &token_admin);
+    let auction_contract = create_auction_contract(&env);
+    let bidders = [
+        Address::random(&env),
+        Address::random(&env),
+        Address::random(&env),
+    ];
+
+    // Initialize the balances.
+    token_admin_client.mint(&seller, &1);
+    token_admin_client.mint(&other_seller, &1);
+    for bidder in bidders.iter() {
+        market_admin_client.mint(bidder, &initial_balance);
+    }
+
+    auction_contract.initialize(&token_admin, &60, &commission_rate, &true);
+    let auction_id = auction_contract.schedule(
+        &seller,
+        &token.address,
+        &1,
+        &vec![&env],
+        &TimingData { start_time: 1, duration: 999 },
+        &market.address,
+        &100,
+        &0,
+        &AuctionBehavior::AscendingPrice,
+        &no_discount(),
+    );
+    let other_auction_id = auction_contract.schedule(
+        &other_seller,
+        &token.address,
+        &1,
+        &vec![&env],
+        &TimingData { start_time: 2, duration: 998 },
+        &market.address,
+        &100,
+        &0,
+        &AuctionBehavior::AscendingPrice,
+        &no_discount(),
+    );
+
+    // Percentage increment, set before the auction opens.
+    auction_contract.set_bid_increment(&auction_id, &BidIncrement::Percent(10));
+    env.ledger().with_mut(|li| li.timestamp = 1);
+    auction_contract.place_bid(&auction_id, &bidders[0], &200);
+    let result = catch_unwind(AssertUnwindSafe(|| {
+        auction_contract.place_bid(&auction_id, &bidders[1], &219);
+    }));
+    assert!(result.is_err(), "Invalid bid amount.");
+    auction_contract.place_bid(&auction_id, &bidders[1], &220);
+
+    // Increment cannot change once the auction opens.
+    let result = catch_unwind(AssertUnwindSafe(|| {
+        auction_contract.set_bid_increment(&auction_id, &BidIncrement::Absolute(1));
+    }));
+    assert!(result.is_err(), "Not allowed to change bid increment.");
+
+    // Tiered increment by price band, bands must start at zero.
+    let result = catch_unwind(AssertUnwindSafe(|| {
+        auction_contract.set_bid_increment(
+            &other_auction_id,
+            &BidIncrement::Tiered(vec![
+                &env,
+                IncrementTierData {
+                    from_price: 500,
+                    increment: 50,
+                },
+            ]),
+        );
+    }));
+    assert!(result.is_err(), "Invalid auction parameters.");
+    auction_contract.set_bid_increment(
+        &other_auction_id,
+        &BidIncrement::Tiered(vec![
+            &env,
+            IncrementTierData {
+                from_price: 0,
+                increment: 10,
+            },
+            IncrementTierData {
+                from_price: 500,
+                increment: 50,
+            },
+        ]),
+    );
+    env.ledger().with_mut(|li| li.timestamp = 2);
+    auction_contract.place_bid(&other_auction_id, &bidders[0], &490);
+    let result = catch_unwind(AssertUnwindSafe(|| {
+        auction_contract.place_bid(&other_auction_id, &bidders[1], &495);
+    }));
+    assert!(result.is_err(), "Invalid bid amount.");
+    auction_contract.place_bid(&other_auction_id, &bidders[1], &500);
+    let result = catch_unwind(AssertUnwindSafe(|| {
+        auction_contract.place_bid(&other_auction_id, &bidders[2], &540);
+    }));
+    assert!(result.is_err(), "Invalid bid amount.");
+    auction_contract.place_bid(&other_auction_id, &bidders[2], &550);
+    assert_eq!(auction_contract.get_auction(&other_auction_id).unwrap().bids.len(), 3);
+}
+
+#[test]
+fn test_proxy_bid() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let initial_balance = 10000;
+    let commission_rate = 10;
+    let token_admin = Address::random(&env);
+    let seller = Address::random(&env);
+    let (token, token_admin_client) = create_token_contract(&env, &token_admin);
+    let (market, market_admin_client) = create_token_contract(&env, &token_admin);
+    let auction_contract = create_auction_contract(&env);
+    let bidders = [
+        Address::random(&env),
+        Address::random(&env),
+        Address::random(&env),
+    ];
+
+    // Initialize the balances.
+    token_admin_client.mint(&seller, &1);
+    for bidder in bidders.iter() {
+        market_admin_client.mint(bidder, &initial_balance);
+    }
+
+    auction_contract.initialize(&token_admin, &60, &commission_rate, &true);
+    let auction_id = auction_contract.schedule(
+        &seller,
+        &token.address,
+        &1,
+        &vec![&env],
+        &TimingData { start_time: 1, duration: 999 },
+        &market.address,
+        &100,
+        &0,
+        &AuctionBehavior::AscendingPrice,
+        &no_discount(),
+    );
+    auction_contract.set_bid_increment(&auction_id, &BidIncrement::Absolute(10));
+    env.ledger().with_mut(|li| li.timestamp = 1);
+
+    // Proxy bid escrows the maximum and bids the reserve.
+    auction_contract.place_proxy_bid(&auction_id, &bidders[0], &500);
+    assert_eq!(market.balance(&bidders[0]), initial_balance - 500);
+    let bid_amount = |buyer: &Address| {
+        auction_contract
+            .get_auction(&auction_id)
+            .unwrap()
+            .bids
+            .iter()
+            .find(|bid| bid.buyer == *buyer)
+            .unwrap()
+            .amount
+    };
+    assert_eq!(bid_amount(&bidders[0]), 100);
+
+    // Proxy bid is raised to outbid competing bids by the increment.
+    auction_contract.place_bid(&auction_id, &bidders[1], &200);
+    assert_eq!(bid_amount(&bidders[0]), 210);
+    auction_contract.place_proxy_bid(&auction_id, &bidders[2], &300);
+    assert_eq!(bid_amount(&bidders[0]), 310);
+    assert_eq!(bid_amount(&bidders[2]), 300);
+
+    // Cancelled proxy bids are refunded their maximum.
+    auction_contract.place_bid(&auction_id, &bidders[2], &0);
+    assert_eq!(market.balance(&bidders[2]), initial_balance);
+    auction_contract.resolve(&auction_id);
+    assert_eq!(bid_amount(&bidders[0]), 210);
+
+    // Proxy bid wins at the amount needed, the unused escrow is refunded.
+    env.ledger().with_mut(|li| li.timestamp = 1001);
+    auction_contract.resolve(&auction_id);
+    assert!(auction_contract.get_auction(&auction_id).is_none());
+    assert_eq!(token.balance(&bidders[0]), 1);
+    assert_eq!(market.balance(&bidders[0]), initial_balance - 210);
+    assert_eq!(market.balance(&bidders[1]), initial_balance);
+    assert_eq!(market.balance(&seller), 210 * (100 - commission_rate) / 100);
+}
+
+#[test]
+fn test_raise_bid() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let initial_balance = 10000;
+    let commission_rate = 10;
+    let token_admin = Address::random(&env);
+    let seller = Address::random(&env);
+    let (token, token_admin_client) = create_token_contract(&env, &token_admin);
+    let (market, market_admin_client) = create_token_contract(&env, &token_admin);
+    let auction_contract = create_auction_contract(&env);
+    let bidders = [Address::random(&env), Address::random(&env)];
+
+    // Initialize the balances.
+    token_admin_client.mint(&seller, &1);
+    for bidder in bidders.iter() {
+        market_admin_client.mint(bidder, &initial_balance);
+    }
+
+    auction_contract.initialize(&token_admin, &60, &commission_rate, &true);
+    let auction_id = auction_contract.start(
+        &seller, &token.address, &1, &1000, &market.address, &100, &0, &0, &0, &false,
+    );
+
+    auction_contract.place_bid(&auction_id, &bidders[0], &200);
+    auction_contract.place_bid(&auction_id, &bidders[1], &300);
+
+    // Raise transfers only the difference.
+    auction_contract.raise_bid(&auction_id, &bidders[0], &400);
+    assert_eq!(market.balance(&bidders[0]), initial_balance - 400);
+    let result = catch_unwind(AssertUnwindSafe(|| {
+        auction_contract.raise_bid(&auction_id, &bidders[0], &350);
+    }));
+    assert!(result.is_err(), "Invalid bid amount.");
+
+    // Snipers can raise their bid, extending the auction.
+    env.ledger().with_mut(|li| li.timestamp = 950);
+    auction_contract.raise_bid(&auction_id, &bidders[1], &500);
+    let test_auction = auction_contract.get_auction(&auction_id).unwrap();
+    assert_eq!(test_auction.duration, 1060);
+    assert!(test_auction.bids.get_unchecked(1).sniper);
+    let result = catch_unwind(AssertUnwindSafe(|| {
+        auction_contract.place_bid(&auction_id, &bidders[1], &0);
+    }));
+    assert!(result.is_err(), "No bid to cancel.");
+    auction_contract.raise_bid(&auction_id, &bidders[1], &600);
+    assert_eq!(market.balance(&bidders[1]), initial_balance - 600);
+
+    env.ledger().with_mut(|li| li.timestamp = 1121);
+    auction_contract.resolve(&auction_id);
+    assert!(auction_contract.get_auction(&auction_id).is_none());
+    assert_eq!(token.balance(&bidders[1]), 1);
+    assert_eq!(market.balance(&bidders[1]), initial_balance - 600);
+    assert_eq!(market.balance(&bidders[0]), initial_balance);
+}
+
+#[test]
+fn test_tie_break() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let initial_balance = 10000;
+    let commission_rate = 10;
+    let token_admin = Address::random(&env);
+    let seller = Address::random(&env);
+    let other_seller = Address::random(&env);
+    let (token, token_admin_client) = create_token_contract(&env, &token_admin);
+    let (market, market_admin_client) = create_token_contract(&env, &token_admin);
+    let auction_contract = create_auction_contract(&env);
+    let bidders = [Address::random(&env), Address::random(&env)];
+
+    // Initialize the balances.
+    token_admin_client.mint(&seller, &1);
+    token_admin_client.mint(&other_seller, &1);
+    for bidder in bidders.iter() {
+        market_admin_client.mint(bidder, &initial_balance);
+    }
+
+    auction_contract.initialize(&token_admin, &60, &commission_rate, &true);
+    let auction_id = auction_contract.start(
+        &seller, &token.address, &1, &1000, &market.address, &100, &0, &0, &0, &false,
+    );
+
+    // Ties are rejected, set before the auction opens.
+    let other_auction_id = auction_contract.schedule(
+        &other_seller,
+        &token.address,
+        &1,
+        &vec![&env],
+        &TimingData { start_time: 1, duration: 999 },
+        &market.address,
+        &100,
+        &0,
+        &AuctionBehavior::AscendingPrice,
+        &no_discount(),
+    );
+    auction_contract.set_tie_break(&other_auction_id, &TieBreak::Rejected);
+
+    // Bids record their ledger time and sequence.
+    env.ledger().with_mut(|li| li.timestamp = 10);
+    auction_contract.place_bid(&auction_id, &bidders[0], &200);
+    env.ledger().with_mut(|li| li.timestamp = 20);
+    auction_contract.place_bid(&auction_id, &bidders[1], &200);
+    let test_auction = auction_contract.get_auction(&auction_id).unwrap();
+    assert_eq!(test_auction.bids.get_unchecked(0).timestamp, 10);
+    assert_eq!(test_auction.bids.get_unchecked(0).sequence, 0);
+    assert_eq!(test_auction.bids.get_unchecked(1).timestamp, 20);
+    assert_eq!(test_auction.bids.get_unchecked(1).sequence, 1);
+
+    // Earliest of the tied bids wins.
+    env.ledger().with_mut(|li| li.timestamp = 1001);
+    auction_contract.resolve(&auction_id);
+    assert_eq!(token.balance(&bidders[0]), 1);
+    assert_eq!(market.balance(&bidders[1]), initial_balance);
+
+    // Ties are rejected.
+    env.ledger().with_mut(|li| li.timestamp = 1);
+    auction_contract.place_bid(&other_auction_id, &bidders[0], &200);
+    let result = catch_unwind(AssertUnwindSafe(|| {
+        auction_contract.place_bid(&other_auction_id, &bidders[1], &200);
+    }));
+    assert!(result.is_err(), "Tied bids not allowed.");
+    let result = catch_unwind(AssertUnwindSafe(|| {
+        auction_contract.set_tie_break(&other_auction_id, &TieBreak::Earliest);
+    }));
+    assert!(result.is_err(), "Not allowed to change tie-break.");
+
+    // Multi-unit auctions compare unit prices.
+    let unit_seller = Address::random(&env);
+    let unit_bidder = Address::random(&env);
+    token_admin_client.mint(&unit_seller, &3);
+    market_admin_client.mint(&unit_bidder, &initial_balance);
+    let unit_auction_id = auction_contract.schedule(
+        &unit_seller,
+        &token.address,
+        &3,
+        &vec![&env],
+        &TimingData { start_time: 2, duration: 998 },
+        &market.address,
+        &10,
+        &0,
+        &AuctionBehavior::UniformPrice,
+        &no_discount(),
+    );
+    auction_contract.set_tie_break(&unit_auction_id, &TieBreak::Rejected);
+    env.ledger().with_mut(|li| li.timestamp = 2);
+    auction_contract.place_unit_bid(&unit_auction_id, &bidders[0], &2, &100);
+    auction_contract.place_unit_bid(&unit_auction_id, &bidders[1], &1, &200);
+    let result = catch_unwind(AssertUnwindSafe(|| {
+        auction_contract.place_unit_bid(&unit_auction_id, &unit_bidder, &1, &100);
+    }));
+    assert!(result.is_err(), "Tied bids not allowed.");
+
+    // Sealed-bid auctions do not apply the tie-break policy.
+    let sealed_seller = Address::random(&env);
+    token_admin_client.mint(&sealed_seller, &1);
+    let sealed_auction_id = auction_contract.schedule(
+        &sealed_seller,
+        &token.address,
+        &1,
+        &vec![&env],
+        &TimingData { start_time: 3, duration: 997 },
+        &market.address,
+        &100,
+        &0,
+        &AuctionBehavior::SealedBid(100),
+        &no_discount(),
+    );
+    let result = catch_unwind(AssertUnwindSafe(|| {
+        auction_contract.set_tie_break(&sealed_auction_id, &TieBreak::Rejected);
+    }));
+    assert!(result.is_err(), "Not allowed to change tie-break.");
+}
+
+#[test]
+fn test_cancellation() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let initial_balance = 10000;
+    let commission_rate = 10;
+    let token_admin = Address::random(&env);
+    let seller = Address::random(&env);
+    let (token, token_admin_client) = create_token_contract(&env, &token_admin);
+    let (market, market_admin_client) = create_token_contract(&env, &token_admin);
+    let auction_contract = create_auction_contract(&env);
+    let bidders = [Address::random(&env), Address::random(&env)];
+
+    // Initialize the balances.
+    token_admin_client.mint(&seller, &1);
+    for bidder in bidders.iter() {
+        market_admin_client.mint(bidder, &initial_balance);
+    }
+
+    auction_contract.initialize(&token_admin, &60, &commission_rate, &true);
+    let auction_id = auction_contract.schedule(
+        &seller,
+        &token.address,
+        &1,
+        &vec![&env],
+        &TimingData { start_time: 1, duration: 999 },
+        &market.address,
+        &100,
+        &0,
+        &AuctionBehavior::AscendingPrice,
+        &no_discount(),
+    );
+    let cancellation = CancellationData {
+        free_window: 100,
+        fee_percent: 10,
+        fee_to_seller: true,
+        cutoff: 300,
+    };
+    auction_contract.set_cancellation(&auction_id, &cancellation);
+
+    // Policy is final once the auction opens.
+    env.ledger().with_mut(|li| li.timestamp = 1);
+    let result = catch_unwind(AssertUnwindSafe(|| {
+        auction_contract.set_cancellation(&auction_id, &cancellation);
+    }));
+    assert!(result.is_err(), "Not allowed to change cancellation.");
+
+    // Free retraction within the window.
+    auction_contract.place_bid(&auction_id, &bidders[0], &200);
+    env.ledger().with_mut(|li| li.timestamp = 100);
+    auction_contract.place_bid(&auction_id, &bidders[0], &0);
+    assert_eq!(market.balance(&bidders[0]), initial_balance);
+
+    // Cancellation fee is kept by the seller after the window.
+    auction_contract.place_bid(&auction_id, &bidders[0], &200);
+    env.ledger().with_mut(|li| li.timestamp = 201);
+    auction_contract.place_bid(&auction_id, &bidders[0], &0);
+    assert_eq!(market.balance(&bidders[0]), initial_balance - 20);
+    assert_eq!(market.balance(&seller), 20);
+
+    // Raising does not restart the free window.
+    auction_contract.place_bid(&auction_id, &bidders[0], &200);
+    env.ledger().with_mut(|li| li.timestamp = 300);
+    auction_contract.raise_bid(&auction_id, &bidders[0], &201);
+    env.ledger().with_mut(|li| li.timestamp = 302);
+    auction_contract.place_bid(&auction_id, &bidders[0], &0);
+    assert_eq!(market.balance(&bidders[0]), initial_balance - 40);
+    assert_eq!(market.balance(&seller), 40);
+
+    // Bids are irrevocable past the cutoff.
+    auction_contract.place_bid(&auction_id, &bidders[1], &300);
+    env.ledger().with_mut(|li| li.timestamp = 700);
+    let result = catch_unwind(AssertUnwindSafe(|| {
+        auction_contract.place_bid(&auction_id, &bidders[1], &0);
+    }));
+    assert!(result.is_err(), "Bid is irrevocable.");
+    assert_eq!(market.balance(&bidders[1]), initial_balance - 300);
+}
+
+#[test]
+fn test_gating() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let initial_balance = 10000;
+    let commission_rate = 10;
+    let token_admin = Address::random(&env);
+    let seller = Address::random(&env);
+    let other_seller = Address::random(&env);
+    let (token, token_admin_client) = create_token_contract(&env, &token_admin);
+    let (market, market_admin_client) = create_token_contract(&env, &token_admin);
+    let (pass, pass_admin_client) = create_token_contract(&env, &token_admin);
+    let auction_contract = create_auction_contract(&env);
+    let bidders = [Address::random(&env), Address::random(&env)];
+
+    // Initialize the balances.
+    token_admin_client.mint(&seller, &1);
+    token_admin_client.mint(&other_seller, &1);
+    pass_admin_client.mint(&bidders[0], &1);
+    for bidder in bidders.iter() {
+        market_admin_client.mint(bidder, &initial_balance);
+    }
+
+    auction_contract.initialize(&token_admin, &60, &commission_rate, &true);
+
+    // Allowlisted buyers only, set before the auction opens.
+    let auction_id = auction_contract.schedule(
+        &seller,
+        &token.address,
+        &1,
+        &vec![&env],
+        &TimingData { start_time: 1, duration: 1000 },
+        &market.address,
+        &100,
+        &0,
+        &AuctionBehavior::AscendingPrice,
+        &no_discount(),
+    );
+    auction_contract.set_gating(&auction_id, &Gating::Allowlist(vec![&env, bidders[1].clone()]));
+    env.ledger().with_mut(|li| li.timestamp = 1);
+    let result = catch_unwind(AssertUnwindSafe(|| {
+        auction_contract.place_bid(&auction_id, &bidders[0], &200);
+    }));
+    assert!(result.is_err(), "Buyer not allowed.");
+    auction_contract.place_bid(&auction_id, &bidders[1], &200);
+
+    // Gating is final once the auction opens.
+    let result = catch_unwind(AssertUnwindSafe(|| {
+        auction_contract.set_gating(&auction_id, &Gating::Open);
+    }));
+    assert!(result.is_err(), "Not allowed to change gating.");
+
+    // Holders only, set as marketplace default.
+    auction_contract.set_default_gating(&Gating::TokenHolder(TokenGateData {
+        token: pass.address.clone(),
+        min_balance: 1,
+    }));
+    let other_auction_id = auction_contract.start(
+        &other_seller, &token.address, &1, &1000, &market.address, &100, &0, &0, &0, &false,
+    );
+    let result = catch_unwind(AssertUnwindSafe(|| {
+        auction_contract.set_gating(&other_auction_id, &Gating::Open);
+    }));
+    assert!(result.is_err(), "Not allowed to change gating.");
+    let result = catch_unwind(AssertUnwindSafe(|| {
+        auction_contract.place_bid(&other_auction_id, &bidders[1], &200);
+    }));
+    assert!(result.is_err(), "Buyer not allowed.");
+    auction_contract.place_bid(&other_auction_id, &bidders[0], &200);
+    assert_eq!(auction_contract.get_auction(&other_auction_id).unwrap().bids.len(), 1);
+}
+
+#[test]
+fn test_soft_close() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let initial_balance = 10000;
+    let commission_rate = 10;
+    let token_admin = Address::random(&env);
+    let seller = Address::random(&env);
+    let (token, token_admin_client) = create_token_contract(&env, &token_admin);
+    let (market, market_admin_client) = create_token_contract(&env, &token_admin);
+    let auction_contract = create_auction_contract(&env);
+    let bidders = [
+        Address::random(&env),
+        Address::random(&env),
+        Address::random(&env),
+    ];
+
+    // Initialize the balances.
+    token_admin_client.mint(&seller, &1);
+    for bidder in bidders.iter() {
+        market_admin_client.mint(bidder, &initial_balance);
+    }
+
+    auction_contract.initialize(&token_admin, &60, &commission_rate, &true);
+    let auction_id = auction_contract.schedule(
+        &seller,
+        &token.address,
+        &1,
+        &vec![&env],
+        &TimingData { start_time: 1, duration: 9999 },
+        &market.address,
+        &100,
+        &0,
+        &AuctionBehavior::AscendingPrice,
+        &no_discount(),
+    );
+
+    // Window must be within the admin range.
+    let soft_close = SoftCloseData {
+        window: 600,
+        reset: true,
+        max_extensions: 2,
+        extensions: 0,
+    };
+    let result = catch_unwind(AssertUnwindSafe(|| {
+        auction_contract.set_soft_close(&auction_id, &soft_close);
+    }));
+    assert!(result.is_err(), "Invalid auction parameters.");
+    auction_contract.set_soft_close_range(&60, &3600);
+    auction_contract.set_soft_close(&auction_id, &soft_close);
+
+    // Policy is final once the auction opens.
+    env.ledger().with_mut(|li| li.timestamp = 1);
+    let result = catch_unwind(AssertUnwindSafe(|| {
+        auction_contract.set_soft_close(&auction_id, &soft_close);
+    }));
+    assert!(result.is_err(), "Not allowed to change soft-close.");
+
+    // Late bids reset the end to now + window.
+    env.ledger().with_mut(|li| li.timestamp = 9500);
+    auction_contract.place_bid(&auction_id, &bidders[0], &200);
+    assert_eq!(auction_contract.get_auction(&auction_id).unwrap().duration, 10099);
+    env.ledger().with_mut(|li| li.timestamp = 10000);
+    auction_contract.place_bid(&auction_id, &bidders[1], &300);
+    assert_eq!(auction_contract.get_auction(&auction_id).unwrap().duration, 10599);
+
+    // No more extensions past the maximum.
+    env.ledger().with_mut(|li| li.timestamp = 10500);
+    auction_contract.place_bid(&auction_id, &bidders[2], &400);
+    let test_auction = auction_contract.get_auction(&auction_id).unwrap();
+    assert_eq!(test_auction.duration, 10599);
+    assert!(test_auction.bids.get_unchecked(2).sniper);
+
+    env.ledger().with_mut(|li| li.timestamp = 10601);
+    auction_contract.resolve(&auction_id);
+    assert_eq!(token.balance(&bidders[2]), 1);
+
+    // Auctions without anti-snipe extensions reject soft-close.
+    let candle_seller = Address::random(&env);
+    token_admin_client.mint(&candle_seller, &1);
+    let candle_auction_id = auction_contract.schedule(
+        &candle_seller,
+        &token.address,
+        &1,
+        &vec![&env],
+        &TimingData { start_time: 20000, duration: 1000 },
+        &market.address,
+        &100,
+        &0,
+        &AuctionBehavior::Candle(100),
+        &no_discount(),
+    );
+    let result = catch_unwind(AssertUnwindSafe(|| {
+        auction_contract.set_soft_close(&candle_auction_id, &soft_close);
+    }));
+    assert!(result.is_err(), "Not allowed to change soft-close.");
+}
+
+#[test]
+fn test_scheduled() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let initial_balance = 10000;
+    let commission_rate = 10;
+    let token_admin = Address::random(&env);
+    let seller = Address::random(&env);
+    let (token, token_admin_client) = create_token_contract(&env, &token_admin);
+    let (market, market_admin_client) = create_token_contract(&env, &token_admin);
+    let auction_contract = create_auction_contract(&env);
+    let bidder = Address::random(&env);
+
+    // Initialize the balances.
+    token_admin_client.mint(&seller, &1);
+    market_admin_client.mint(&bidder, &initial_balance);
+
+    auction_contract.initialize(&token_admin, &60, &commission_rate, &true);
+    let auction_id = auction_contract.schedule(
+        &seller,
+        &token.address,
+        &1,
+        &vec![&env],
+        &TimingData { start_time: 1000, duration: 1000 },
+        &market.address,
+        &100,
+        &0,
+        &AuctionBehavior::AscendingPrice,
+        &no_discount(),
+    );
+    assert_eq!(token.balance(&seller), 0);
+
+    // No bids before opening.
+    let result = catch_unwind(AssertUnwindSafe(|| {
+        auction_contract.place_bid(&auction_id, &bidder, &200);
+    }));
+    assert!(result.is_err(), "Auction not started.");
+
+    // Edits before opening are checked as when starting.
+    let result = catch_unwind(AssertUnwindSafe(|| {
+        auction_contract.edit_scheduled(
+            &auction_id,
+            &TimingData { start_time: 2000, duration: 500 },
+            &100,
+            &0,
+            &AuctionBehavior::FixedPrice(false),
+            &no_discount(),
+        );
+    }));
+    assert!(result.is_err(), "Invalid auction parameters.");
+    auction_contract.edit_scheduled(
+        &auction_id,
+        &TimingData { start_time: 2000, duration: 500 },
+        &100,
+        &300,
+        &AuctionBehavior::FixedPrice(false),
+        &no_discount(),
+    );
+    auction_contract.set_gating(&auction_id, &Gating::Allowlist(vec![&env, bidder.clone()]));
+    let test_auction = auction_contract.get_auction(&auction_id).unwrap();
+    assert_eq!(test_auction.start_time, 2000);
+    assert_eq!(test_auction.ask_price, 300);
+    assert_eq!(test_auction.behavior, AuctionBehavior::FixedPrice(false));
+    assert_eq!(token.balance(&auction_contract.address), 1);
+
+    // Withdraw before opening returns the lot.
+    auction_contract.cancel(&auction_id);
+    assert_eq!(token.balance(&seller), 1);
+    assert!(auction_contract.get_auction(&auction_id).is_none());
+
+    // Descending price auctions take their discount when scheduled or edited.
+    let discount = DiscountData {
+        discount_percent: 10,
+        discount_frequency: 100,
+        compounded_discount: false,
+    };
+    let result = catch_unwind(AssertUnwindSafe(|| {
+        auction_contract.schedule(
+            &seller,
+            &token.address,
+            &1,
+            &vec![&env],
+            &TimingData { start_time: 1000, duration: 1000 },
+            &market.address,
+            &100,
+            &1000,
+            &AuctionBehavior::DescendingPrice,
+            &no_discount(),
+        );
+    }));
+    assert!(result.is_err(), "Invalid auction parameters.");
+    let auction_id = auction_contract.schedule(
+        &seller,
+        &token.address,
+        &1,
+        &vec![&env],
+        &TimingData { start_time: 1000, duration: 1000 },
+        &market.address,
+        &100,
+        &1000,
+        &AuctionBehavior::DescendingPrice,
+        &discount,
+    );
+    let test_auction = auction_contract.get_auction(&auction_id).unwrap();
+    assert_eq!(test_auction.discount_percent, 10);
+    assert_eq!(test_auction.discount_frequency, 100);
+    auction_contract.edit_scheduled(
+        &auction_id,
+        &TimingData { start_time: 1000, duration: 1000 },
+        &100,
+        &1000,
+        &AuctionBehavior::DescendingPrice,
+        &DiscountData {
+            discount_percent: 20,
+            discount_frequency: 50,
+            compounded_discount: true,
+        },
+    );
+    let test_auction = auction_contract.get_auction(&auction_id).unwrap();
+    assert_eq!(test_auction.discount_percent, 20);
+    assert_eq!(test_auction.discount_frequency, 50);
+    assert!(test_auction.compounded_discount);
+    auction_contract.cancel(&auction_id);
+    assert_eq!(token.balance(&seller), 1);
+
+    // Bids are accepted once opened, no more edits.
+    let auction_id = auction_contract.schedule(
+        &seller,
+        &token.address,
+        &1,
+        &vec![&env],
+        &TimingData { start_time: 1000, duration: 1000 },
+        &market.address,
+        &100,
+        &0,
+        &AuctionBehavior::AscendingPrice,
+        &no_discount(),
+    );
+    env.ledger().with_mut(|li| li.timestamp = 1000);
+    auction_contract.place_bid(&auction_id, &bidder, &200);
+    let result = catch_unwind(AssertUnwindSafe(|| {
+        auction_contract.cancel(&auction_id);
+    }));
+    assert!(result.is_err(), "Auction already started.");
+
+    env.ledger().with_mut(|li| li.timestamp = 2001);
+    auction_contract.resolve(&auction_id);
+    assert_eq!(token.balance(&bidder), 1);
+}
+
+#[test]
+fn test_cancel() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let initial_balance = 10000;
+    let commission_rate = 10;
+    let token_admin = Address::random(&env);
+    let seller = Address::random(&env);
+    let (token, token_admin_client) = create_token_contract(&env, &token_admin);
+    let (market, market_admin_client) = create_token_contract(&env, &token_admin);
+    let auction_contract = create_auction_contract(&env);
+    let bidders = [Address::random(&env), Address::random(&env)];
+
+    // Initialize the balances.
+    token_admin_client.mint(&seller, &1);
+    for bidder in bidders.iter() {
+        market_admin_client.mint(bidder, &initial_balance);
+    }
+
+    auction_contract.initialize(&token_admin, &60, &commission_rate, &true);
+
+    // Cancel without bids.
+    let auction_id = auction_contract.start(
+        &seller, &token.address, &1, &1000, &market.address, &100, &0, &0, &0, &false,
+    );
+    auction_contract.cancel(&auction_id);
+    assert_eq!(token.balance(&seller), 1);
+    assert_eq!(auction_contract.get_auction(&auction_id), None);
+    let cancelled_topics: soroban_sdk::Vec<soroban_sdk::Val> =
+        (symbol_short!("AUCTION"), symbol_short!("cancelled")).into_val(&env);
+    let ended_topics: soroban_sdk::Vec<soroban_sdk::Val> =
+        (symbol_short!("AUCTION"), symbol_short!("ended")).into_val(&env);
+    assert!(env
+        .events()
+        .all()
+        .iter()
+        .any(|(_, topics, _)| topics == cancelled_topics));
+    assert!(!env
+        .events()
+        .all()
+        .iter()
+        .any(|(_, topics, _)| topics == ended_topics));
+
+    // Cancel with bids only if enabled by the admin.
+    let auction_id = auction_contract.start(
+        &seller, &token.address, &1, &1000, &market.address, &100, &0, &0, &0, &false,
+    );
+    auction_contract.place_bid(&auction_id, &bidders[0], &200);
+    auction_contract.place_proxy_bid(&auction_id, &bidders[1], &500);
+    let result = catch_unwind(AssertUnwindSafe(|| {
+        auction_contract.cancel(&auction_id);
+    }));
+    assert!(result.is_err(), "Not allowed to cancel auction.");
+
+    auction_contract.set_cancel_with_bids(&true);
+    auction_contract.cancel(&auction_id);
+    assert_eq!(token.balance(&seller), 1);
+    assert_eq!(market.balance(&bidders[0]), initial_balance);
+    assert_eq!(market.balance(&bidders[1]), initial_balance);
+    assert_eq!(market.balance(&auction_contract.address), 0);
+}
+
+#[test]
+fn test_amend() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let initial_balance = 10000;
+    let commission_rate = 10;
+    let token_admin = Address::random(&env);
+    let seller = Address::random(&env);
+    let other_seller = Address::random(&env);
+    let (token, token_admin_client) = create_token_contract(&env, &token_admin);
+    let (market, market_admin_client) = create_token_contract(&env, &token_admin);
+    let auction_contract = create_auction_contract(&env);
+    let bidder = Address::random(&env);
+
+    // Initialize the balances.
+    token_admin_client.mint(&seller, &1);
+    token_admin_client.mint(&other_seller, &1);
+    market_admin_client.mint(&bidder, &initial_balance);
+
+    auction_contract.initialize(&token_admin, &60, &commission_rate, &true);
+    let auction_id = auction_contract.start(
+        &seller, &token.address, &1, &1000, &market.address, &100, &1000, &10, &100, &false,
+    );
+    auction_contract.place_bid(&auction_id, &bidder, &500);
+
+    // Reserve cannot be raised with bids, schedules only apply to schedule-based auctions.
+    let result = catch_unwind(AssertUnwindSafe(|| {
+        auction_contract.amend(&auction_id, &Amendment::ReservePrice(600));
+    }));
+    assert!(result.is_err(), "Not allowed to raise reserve price.");
+    let result = catch_unwind(AssertUnwindSafe(|| {
+        auction_contract.amend(
+            &auction_id,
+            &Amendment::Schedule(PriceScheduleData {
+                breakpoints: vec![&env, BreakpointData { offset: 0, price: 800 }],
+                linear: false,
+            }),
+        );
+    }));
+    assert!(result.is_err(), "Not allowed to amend auction.");
+
+    auction_contract.amend(
+        &auction_id,
+        &Amendment::Discount(DiscountData {
+            discount_percent: 20,
+            discount_frequency: 100,
+            compounded_discount: false,
+        }),
+    );
+    assert_eq!(auction_contract.get_auction(&auction_id).unwrap().discount_percent, 20);
+
+    // Lowering the ask meets the standing bid.
+    auction_contract.amend(&auction_id, &Amendment::AskPrice(500));
+    assert_eq!(auction_contract.get_auction(&auction_id), None);
+    assert_eq!(token.balance(&bidder), 1);
+    let amended_topics: soroban_sdk::Vec<soroban_sdk::Val> =
+        (symbol_short!("AUCTION"), symbol_short!("amended")).into_val(&env);
+    let (_, _, data) = env
+        .events()
+        .all()
+        .iter()
+        .filter(|(_, topics, _)| *topics == amended_topics)
+        .last()
+        .unwrap();
+    let (_, previous, amendment): (u64, Amendment, Amendment) =
+        data.try_into_val(&env).unwrap();
+    assert_eq!(previous, Amendment::AskPrice(1000));
+    assert_eq!(amendment, Amendment::AskPrice(500));
+
+    // No amendments within the soft-close window.
+    let other_auction_id = auction_contract.start(
+        &other_seller, &token.address, &1, &1000, &market.address, &100, &0, &0, &0, &false,
+    );
+    auction_contract.amend(&other_auction_id, &Amendment::ReservePrice(50));
+    assert_eq!(auction_contract.get_auction(&other_auction_id).unwrap().reserve_price, 50);
+    env.ledger().with_mut(|li| li.timestamp = 950);
+    let result = catch_unwind(AssertUnwindSafe(|| {
+        auction_contract.amend(&other_auction_id, &Amendment::ReservePrice(10));
+    }));
+    assert!(result.is_err(), "Not allowed to amend auction.");
+}
+
+#[test]
+fn test_auction_ids() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let initial_balance = 10000;
+    let commission_rate = 10;
+    let token_admin = Address::random(&env);
+    let seller = Address::random(&env);
+    let (token, token_admin_client) = create_token_contract(&env, &token_admin);
+    let (market, market_admin_client) = create_token_contract(&env, &token_admin);
+    let auction_contract = create_auction_contract(&env);
+    let bidder = Address::random(&env);
+
+    // Initialize the balances.
+    token_admin_client.mint(&seller, &2);
+    market_admin_client.mint(&bidder, &initial_balance);
+
+    auction_contract.initialize(&token_admin, &60, &commission_rate, &true);
+
+    // Concurrent auctions from the same seller.
+    let auction_id = auction_contract.start(
+        &seller, &token.address, &1, &1000, &market.address, &100, &0, &0, &0, &false,
+    );
+    let other_auction_id = auction_contract.start_with_behavior(
+        &seller,
+        &token.address,
+        &1,
+        &1000,
+        &market.address,
+        &100,
+        &0,
+        &AuctionBehavior::Offer,
+    );
+    assert_ne!(auction_id, other_auction_id);
+    assert_eq!(
+        auction_contract.get_auctions(&seller),
+        vec![&env, auction_id, other_auction_id]
+    );
+    assert_eq!(auction_contract.get_auction(&auction_id).unwrap().seller, seller);
+
+    // Ended auctions are removed from the seller auctions.
+    auction_contract.place_bid(&other_auction_id, &bidder, &200);
+    auction_contract.accept_offer(&other_auction_id, &bidder);
+    assert_eq!(token.balance(&bidder), 1);
+    assert_eq!(auction_contract.get_auctions(&seller), vec![&env, auction_id]);
+    assert!(auction_contract.get_auction(&auction_id).is_some());
+
+    auction_contract.cancel(&auction_id);
+    assert_eq!(auction_contract.get_auctions(&seller).len(), 0);
+    assert_eq!(token.balance(&seller), 1);
+}
+
 #[contract]
 pub struct TestContract;
 
@@ -6,7 +6,7 @@
     MIT License
 */
 
-use soroban_sdk::{contracttype, Address, Vec};
+use soroban_sdk::{contracttype, Address, BytesN, Vec};
 
 use crate::impl_soroban_storage;
 
@@ -14,7 +14,10 @@ use crate::impl_soroban_storage;
 #[contracttype]
 pub enum DataKey {
     AdminData,
-    AuctionData(Address),
+    AuctionData(u64),
+    SealedBidData(u64),
+    ProxyBidData(u64),
+    SellerData(Address),
 }
 
 #[contracttype]
@@ -22,7 +25,267 @@ pub enum DataKey {
 pub struct BidData {
     pub buyer: Address,
     pub amount: i128,
+    pub quantity: i128,
     pub sniper: bool,
+    // Time of the last placement or raise.
+    pub timestamp: u64,
+    // Time the bid was first placed, unchanged by raises.
+    pub placed_at: u64,
+    pub sequence: u64,
+}
+
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub enum AuctionBehavior {
+    AscendingPrice,
+    DescendingPrice,
+    // Sealed-bid first-price auction with reveal duration (in seconds).
+    SealedBid(u64),
+    // Second-price sealed-bid auction with reveal duration (in seconds).
+    Vickrey(u64),
+    // Candle auction with candle duration (in seconds) ending the auction.
+    Candle(u64),
+    // Multi-unit auction where all winners pay the lowest accepted unit price.
+    UniformPrice,
+    // Multi-unit auction where each winner pays its own unit price.
+    DiscriminatoryPrice,
+    // Procurement auction where the seller buys the lot from the lowest ask.
+    Reverse,
+    // Ascending clock auction where bidders must stay in each round.
+    Clock(ClockData),
+    // Gradual Dutch auction selling units at an exponentially decaying price.
+    GradualDutch(GradualDutchData),
+    // Variable rate gradual Dutch auction pacing sales on a target schedule.
+    VariableRateGradualDutch(VariableRateGradualDutchData),
+    // Fixed-price listing selling at ask_price per unit, allowing partial purchases if set.
+    FixedPrice(bool),
+    // Negotiated sale where the seller accepts any one of the escrowed offers.
+    Offer,
+    // Descending price auction following a custom price schedule.
+    DescendingPriceSchedule(PriceScheduleData),
+    // Descending price auction switching to ascending once the price is met.
+    DutchEnglish(DutchEnglishData),
+}
+
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct BreakpointData {
+    pub offset: u64,
+    pub price: i128,
+}
+
+// Breakpoints are (time offset from start, price) pairs with increasing offsets,
+// starting at offset 0, and non-increasing prices.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct PriceScheduleData {
+    pub breakpoints: Vec<BreakpointData>,
+    pub linear: bool,
+}
+
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct ClockData {
+    pub increment: i128,
+    pub round_duration: u64,
+}
+
+// Bidding window (in seconds) opened once the descending price is met.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct DutchEnglishData {
+    pub schedule: PriceScheduleData,
+    pub bidding_window: u64,
+    pub english: bool,
+}
+
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct FillData {
+    pub buyer: Address,
+    pub quantity: i128,
+    pub price: i128,
+}
+
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct CommitmentData {
+    pub buyer: Address,
+    pub commitment: BytesN<32>,
+    pub deposit: i128,
+    pub revealed: bool,
+}
+
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct SealedBidData {
+    pub commitments: Vec<CommitmentData>,
+}
+
+// Implement SealedBidData with Persistent storage.
+impl_soroban_storage!(SealedBidData, Persistent);
+
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct ProxyMaximumData {
+    pub buyer: Address,
+    pub maximum: i128,
+}
+
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct ProxyBidData {
+    pub maximums: Vec<ProxyMaximumData>,
+}
+
+// Implement ProxyBidData with Persistent storage.
+impl_soroban_storage!(ProxyBidData, Persistent);
+
+// Auctions started by a seller.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct SellerData {
+    pub auctions: Vec<u64>,
+}
+
+// Implement SellerData with Persistent storage.
+impl_soroban_storage!(SellerData, Persistent);
+
+// Fixed-point values have 18 decimals (see: auctions/fixed_point.rs).
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct GradualDutchData {
+    pub decay_constant: i128,
+    pub scale_factor: i128,
+    pub sold: i128,
+}
+
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct VariableRateGradualDutchData {
+    pub decay_constant: i128,
+    pub units_per_second: i128,
+    pub sold: i128,
+}
+
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct LotData {
+    pub token: Address,
+    pub amount: i128,
+}
+
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub enum BidIncrement {
+    None,
+    // Fixed amount above the best bid.
+    Absolute(i128),
+    // Percentage of the best bid.
+    Percent(u32),
+    // Fixed amounts by price band, with increasing from_price starting at 0.
+    Tiered(Vec<IncrementTierData>),
+}
+
+// Cancellations are free within free_window (in seconds) after placing the bid,
+// then charged fee_percent of the bid, paid to the seller or the admin.
+// Bids are irrevocable within cutoff (in seconds) from the auction end.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct CancellationData {
+    pub free_window: u64,
+    pub fee_percent: u32,
+    pub fee_to_seller: bool,
+    pub cutoff: u64,
+}
+
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub enum CancellationPolicy {
+    // Bids can be cancelled at any time (except anti-snipe).
+    Free,
+    Restricted(CancellationData),
+}
+
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub enum Gating {
+    Open,
+    // Only listed buyers may bid.
+    Allowlist(Vec<Address>),
+    // Only holders of a minimum balance of the token may bid.
+    TokenHolder(TokenGateData),
+}
+
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct TokenGateData {
+    pub token: Address,
+    pub min_balance: i128,
+}
+
+// Soft-close window (in seconds) before the auction end. Late bids either extend
+// the end by the window or reset it to now + window, at most max_extensions
+// times (0 for unlimited).
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct SoftCloseData {
+    pub window: u64,
+    pub reset: bool,
+    pub max_extensions: u32,
+    pub extensions: u32,
+}
+
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub enum SoftClose {
+    // Admin anti_snipe_time, late bids extend the end by the window.
+    Default,
+    Custom(SoftCloseData),
+}
+
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub enum TieBreak {
+    // Earliest of the tied bids wins.
+    Earliest,
+    // Bids tying an existing bid are rejected.
+    Rejected,
+}
+
+// Discount rate of descending price auctions (see: start).
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct DiscountData {
+    pub discount_percent: u32,
+    pub discount_frequency: u64,
+    pub compounded_discount: bool,
+}
+
+// Opening time and duration of scheduled auctions (see: schedule).
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct TimingData {
+    pub start_time: u64,
+    pub duration: u64,
+}
+
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub enum Amendment {
+    ReservePrice(i128),
+    AskPrice(i128),
+    // Discount rate of descending price auctions.
+    Discount(DiscountData),
+    // Price schedule of descending price schedule and Dutch-English auctions.
+    Schedule(PriceScheduleData),
+}
+
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct IncrementTierData {
+    pub from_price: i128,
+    pub increment: i128,
 }
 
 #[contracttype]
@@ -32,6 +295,11 @@ pub struct AdminData {
     pub anti_snipe_time: u64,
     pub commission_rate: i128,
     pub extendable_auctions: bool,
+    pub default_gating: Gating,
+    pub min_soft_close: u64,
+    pub max_soft_close: u64,
+    pub cancel_with_bids: bool,
+    pub auction_counter: u64,
 }
 
 // Implement AdminData with Instance storage.
@@ -40,8 +308,10 @@ impl_soroban_storage!(AdminData, Instance);
 #[derive(Clone, Debug, Eq, PartialEq)]
 #[contracttype]
 pub struct AuctionData {
+    pub seller: Address,
     pub token: Address,
     pub amount: i128,
+    pub bundle: Vec<LotData>,
     pub duration: u64,
     pub start_time: u64,
     pub market: Address,
@@ -50,6 +320,13 @@ pub struct AuctionData {
     pub discount_percent: u32,
     pub discount_frequency: u64,
     pub compounded_discount: bool,
+    pub bid_increment: BidIncrement,
+    pub tie_break: TieBreak,
+    pub cancellation: CancellationPolicy,
+    pub gating: Gating,
+    pub soft_close: SoftClose,
+    pub bid_sequence: u64,
+    pub behavior: AuctionBehavior,
     pub bids: Vec<BidData>,
 }
 